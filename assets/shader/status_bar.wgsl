@@ -6,6 +6,7 @@ const COLOR_VOID = vec4<f32>(0.0, 0.0, 0.0, 0.333333);
 const COLOR_STAMINA = vec4<f32>(0.8, 0.8, 0.8, 0.4);
 const COLOR_HEALTH = vec4<f32>(1.0, 0.0, 0.0, 0.6);
 const COLOR_AMMO = vec4<f32>(0.8, 0.8, 0.8, 0.4);
+const COLOR_ARMOR = vec4<f32>(0.3, 0.6, 1.0, 0.5);
 
 struct Uniform {
     health: f32,
@@ -13,6 +14,8 @@ struct Uniform {
     ammo: f32,
     ammo_alpha: f32,
     stamina: f32,
+    armor: f32,
+    armor_alpha: f32,
 };
 
 @group(1) @binding(0)
@@ -78,5 +81,6 @@ fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
     var stamina = bar(uniform.stamina, center, mix_alpha(COLOR_STAMINA, 1.0                 ), 0.62, 0.01);
     var health  = bar(uniform.health , center, mix_alpha(COLOR_HEALTH , uniform.health_alpha), 0.91, 0.06);
     var ammo    = bar(uniform.ammo   , center, mix_alpha(COLOR_AMMO   , uniform.ammo_alpha  ), 1.00, 0.01);
-    return vec4<f32>(stamina + health + ammo);
+    var armor   = bar(uniform.armor  , center, mix_alpha(COLOR_ARMOR  , 1.0                 ), 0.74, 0.01) * uniform.armor_alpha;
+    return vec4<f32>(stamina + health + ammo + armor);
 }
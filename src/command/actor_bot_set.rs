@@ -3,6 +3,7 @@ use bevy::{
     ecs::system::Command,
     prelude::{Entity, World},
 };
+use std::sync::Arc;
 
 pub struct ActorBotSet {
     pub entity: Entity,
@@ -13,10 +14,13 @@ impl Command for ActorBotSet {
     fn apply(self, world: &mut World) {
         let entity_id = u64::from(self.entity.index());
 
-        if let Some(config) = world.get::<Actor>(self.entity).map(|a| a.config.bot) {
+        if let Some(config) = world
+            .get::<Actor>(self.entity)
+            .map(|a| Arc::clone(&a.config))
+        {
             world
                 .entity_mut(self.entity)
-                .insert(Bot::new(config, self.skill, entity_id));
+                .insert(Bot::new(&config.bot, self.skill, entity_id));
         } else {
             log::warn!("Can't set bot. Entity has no actor component");
         }
@@ -4,16 +4,22 @@ use bevy::{
     prelude::{Entity, World},
     time::Time,
 };
+use std::time::Duration;
 
-pub struct ActorMeleeReset(pub Entity);
+/// Stops the current melee attack and delays the next one by the given interval
+pub struct ActorMeleeReset {
+    pub entity: Entity,
+    pub interval: Duration,
+}
 
 impl Command for ActorMeleeReset {
     fn apply(self, world: &mut World) {
         let time = world.resource::<Time>().elapsed();
 
-        if let Some(mut actor) = world.get_mut::<Actor>(self.0) {
+        if let Some(mut actor) = world.get_mut::<Actor>(self.entity) {
             actor.actions.remove(ActorAction::Attack);
-            actor.melee_next = time + actor.config.melee_interval.div_f32(actor.skill);
+            actor.actions.remove(ActorAction::Bash);
+            actor.melee_next = time + self.interval.div_f32(actor.skill);
         }
     }
 }
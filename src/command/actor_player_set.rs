@@ -1,9 +1,7 @@
-use super::LaserSightSet;
 use crate::{
     command::StatusBarSet,
-    component::Player,
+    component::{ConsumableInventory, Player, WeaponInventory},
     data::LAYER_ACTOR_PLAYER,
-    resource::{Config, GameMode},
 };
 use bevy::{
     ecs::system::Command,
@@ -23,17 +21,10 @@ impl Command for ActorPlayerSet {
 
         world
             .entity_mut(self.entity)
-            .insert(Player::new(self.is_controllable));
+            .insert(Player::new(self.is_controllable))
+            .insert(WeaponInventory::default())
+            .insert(ConsumableInventory::default());
 
         StatusBarSet(self.entity).apply(world);
-
-        if world
-            .resource::<Config>()
-            .game
-            .modes
-            .contains(&GameMode::LaserSight)
-        {
-            LaserSightSet(self.entity).apply(world);
-        }
     }
 }
@@ -1,28 +1,64 @@
 use crate::{
-    component::{Actor, ActorConfig, ActorKind, Breath, Collision, Footsteps, Health, Inertia},
+    component::{
+        Actor, ActorConfig, ActorKind, Armor, ArmorConfig, Breath, Collision, ExplosiveConfig,
+        Footsteps, Grenades, Health, Inertia, StatusEffects,
+    },
     data::LAYER_ACTOR,
     model::TransformLite,
+    resource::Registry,
+    util::SmartString,
 };
 use bevy::{
     ecs::system::Command,
     prelude::{AssetServer, Entity, SpriteBundle, World},
 };
+use std::sync::Arc;
 
 pub struct ActorSet {
     pub entity: Entity,
-    pub config: &'static ActorConfig,
+    /// Actor archetype id from the actors registry
+    pub actor: SmartString<'static>,
     pub skill: f32,
     pub transform: TransformLite,
 }
 
 impl Command for ActorSet {
     fn apply(self, world: &mut World) {
-        let texture_path = self.config.get_image_path(0);
+        let Some(config) = world
+            .resource::<Registry<ActorConfig>>()
+            .get(self.actor.as_ref())
+            .map(Arc::clone)
+        else {
+            log::warn!("Can't set actor. Actor {} not found", self.actor.as_ref());
+            return;
+        };
+
+        let texture_path = config.get_image_path(0);
         let texture = world
             .resource::<AssetServer>()
             .get_handle(texture_path)
             .unwrap_or_default();
 
+        let grenades = config.grenade.as_ref().and_then(|id| {
+            let explosive = world.resource::<Registry<ExplosiveConfig>>().get(id);
+
+            if explosive.is_none() {
+                log::warn!("Actor {} has unknown grenade {}", config.id, id);
+            }
+
+            return explosive.map(|e| Grenades::new(Arc::clone(e), config.grenades));
+        });
+
+        let armor = config.armor.as_ref().and_then(|id| {
+            let armor = world.resource::<Registry<ArmorConfig>>().get(id);
+
+            if armor.is_none() {
+                log::warn!("Actor {} has unknown armor {}", config.id, id);
+            }
+
+            return armor.map(|a| Armor::new(Arc::clone(a)));
+        });
+
         let mut entity_mut = world.entity_mut(self.entity);
 
         entity_mut
@@ -32,15 +68,25 @@ impl Command for ActorSet {
                 ..Default::default()
             })
             .insert(Collision {
-                radius: self.config.radius,
+                radius: config.radius,
             })
-            .insert(Inertia::new(self.config.mass))
-            .insert(Actor::new(self.config, self.skill))
-            .insert(Health::new(self.config.health * self.skill))
+            .insert(Inertia::new(config.mass))
+            .insert(Health::new(config.health * self.skill))
+            .insert(StatusEffects::default())
             .insert(Footsteps::default());
 
-        if let ActorKind::Human = self.config.kind {
+        if let ActorKind::Human = config.kind {
             entity_mut.insert(Breath::default());
         }
+
+        if let Some(grenades) = grenades {
+            entity_mut.insert(grenades);
+        }
+
+        if let Some(armor) = armor {
+            entity_mut.insert(armor);
+        }
+
+        entity_mut.insert(Actor::new(config, self.skill));
     }
 }
@@ -0,0 +1,51 @@
+use crate::{
+    component::{AmmoReserve, Player},
+    model::AudioPlay,
+    resource::AudioTracker,
+    util::SmartString,
+};
+use bevy::{
+    ecs::system::Command,
+    math::Vec3Swizzles,
+    prelude::{Entity, Transform, World},
+};
+
+/// Adds rounds to the actor's ammo reserve. An actor without a reserve gets one,
+/// so its ammo becomes limited since then
+pub struct AmmoAdd {
+    pub entity: Entity,
+    pub caliber: SmartString<'static>,
+    pub amount: u16,
+}
+
+impl AmmoAdd {
+    fn play_pickup_sound(&self, world: &mut World) {
+        if let Some(source) = world
+            .get::<Transform>(self.entity)
+            .map(|t| t.translation.xy())
+        {
+            world.resource_mut::<AudioTracker>().queue(AudioPlay {
+                path: "sounds/pickup_weapon".into(),
+                volume: 0.6,
+                source: Some(source),
+                ..AudioPlay::DEFAULT
+            });
+        }
+    }
+}
+
+impl Command for AmmoAdd {
+    fn apply(self, world: &mut World) {
+        if let Some(mut reserve) = world.get_mut::<AmmoReserve>(self.entity) {
+            reserve.add(self.caliber.as_ref(), self.amount);
+        } else {
+            let mut reserve = AmmoReserve::default();
+            reserve.add(self.caliber.as_ref(), self.amount);
+            world.entity_mut(self.entity).insert(reserve);
+        }
+
+        if world.get::<Player>(self.entity).is_some() {
+            self.play_pickup_sound(world);
+        }
+    }
+}
@@ -59,7 +59,7 @@ impl Command for BloodSpawn {
             return;
         };
 
-        if !reserve_decal(world) {
+        if !reserve_decal::<BloodComponent>(world) {
             return;
         }
 
@@ -90,7 +90,8 @@ impl Command for BloodSpawn {
     }
 }
 
-fn reserve_decal(world: &mut World) -> bool {
+/// Makes room for a new decal of the given kind, removing the furthest one from the camera if needed
+pub(super) fn reserve_decal<T: Component>(world: &mut World) -> bool {
     let camera = world
         .query_filtered::<&Transform, With<Camera>>()
         .iter(world)
@@ -102,7 +103,7 @@ fn reserve_decal(world: &mut World) -> bool {
     let mut furthest_distance = 0.0;
 
     for (entity, transform) in world
-        .query_filtered::<(Entity, &Transform), With<BloodComponent>>()
+        .query_filtered::<(Entity, &Transform), With<T>>()
         .iter(world)
     {
         decals += 1;
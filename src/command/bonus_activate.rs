@@ -1,5 +1,5 @@
-use super::WeaponSet;
-use crate::component::Bonus;
+use super::{AmmoAdd, Notify, WeaponAttach, WeaponSet};
+use crate::component::{Bonus, BonusKind, ConsumableInventory, Weapon};
 use bevy::{
     ecs::system::Command,
     prelude::{DespawnRecursiveExt, Entity, World},
@@ -14,15 +14,68 @@ pub struct BonusActivate {
 
 impl Command for BonusActivate {
     fn apply(self, world: &mut World) {
-        let Some(weapon) = world.get::<Bonus>(self.bonus).map(|b| b.weapon) else {
+        let Some(kind) = world.get::<Bonus>(self.bonus).map(|b| b.kind.clone()) else {
             return;
         };
 
-        WeaponSet {
-            entity: self.recipient,
-            weapon: Some(weapon),
+        match kind {
+            BonusKind::Weapon(weapon) => {
+                WeaponSet {
+                    entity: self.recipient,
+                    weapon: Some(weapon.id.clone().into()),
+                }
+                .apply(world);
+            }
+            BonusKind::Ammo { projectile, amount } => {
+                AmmoAdd {
+                    entity: self.recipient,
+                    caliber: projectile.caliber.clone().into(),
+                    amount,
+                }
+                .apply(world);
+            }
+            BonusKind::Attachment(attachment) => {
+                let can_attach = world
+                    .get::<Weapon>(self.recipient)
+                    .map_or(false, |w| w.can_attach(attachment));
+
+                // leave it for later, the recipient may switch to a suitable weapon
+                if !can_attach {
+                    return;
+                }
+
+                WeaponAttach {
+                    entity: self.recipient,
+                    attachment,
+                }
+                .apply(world);
+            }
+            BonusKind::Consumable(item) => {
+                let Some(mut inventory) = world.get_mut::<ConsumableInventory>(self.recipient)
+                else {
+                    return;
+                };
+
+                let was_empty = inventory.is_empty();
+
+                // leave it for later, the recipient may use some
+                if !inventory.add(item) {
+                    return;
+                }
+
+                let mut text_small = format!("{} ({})", item.name(), inventory.get_count(item));
+
+                if was_empty {
+                    text_small.push_str("\nPress [3], [4], [5] or [6] to use consumables");
+                }
+
+                Notify {
+                    text_small: text_small.into(),
+                    ..Default::default()
+                }
+                .apply(world);
+            }
         }
-        .apply(world);
 
         world.entity_mut(self.bonus).despawn_recursive();
     }
@@ -1,18 +1,28 @@
 use crate::{
-    component::{Bonus, BonusImage, BonusLabel, WeaponConfig},
+    component::{
+        Bonus, BonusImage, BonusKind, BonusLabel, Consumable, Player, Weapon, WeaponAttachment,
+        WeaponConfig,
+    },
     data::{FONT_PATH, LAYER_BONUS, PIXELS_PER_METER, TRANSFORM_SCALE},
+    resource::Registry,
 };
 use bevy::{
     ecs::system::Command,
     prelude::{
         AssetServer, BuildWorldChildren, Color, Entity, SpatialBundle, SpriteBundle, Transform,
-        Vec2, Vec3, World,
+        Vec2, Vec3, With, World,
     },
     text::{Text, Text2dBundle, TextAlignment, TextStyle},
     time::Time,
 };
 use derive_more::Constructor;
-use rand::seq::SliceRandom;
+use rand::{seq::SliceRandom, Rng};
+use std::sync::Arc;
+
+const AMMO_CHANCE: f64 = 0.5;
+const ATTACHMENT_CHANCE: f64 = 0.2;
+const CONSUMABLE_CHANCE: f64 = 0.2;
+const AMMO_MAGAZINES: u16 = 2;
 
 #[derive(Constructor)]
 pub struct BonusSpawn {
@@ -22,17 +32,55 @@ pub struct BonusSpawn {
 
 impl Command for BonusSpawn {
     fn apply(self, world: &mut World) {
-        let Some(weapon) = generate_weapon(self.level) else {
+        let Some(weapon) = generate_weapon(world.resource::<Registry<WeaponConfig>>(), self.level)
+        else {
             return;
         };
 
-        let bonus = spawn_bonus(world, self.position, weapon);
-        spawn_image(world, bonus, weapon);
-        spawn_label(world, bonus, weapon);
+        let mut kind = if rand::thread_rng().gen_bool(ATTACHMENT_CHANCE) {
+            generate_attachment(world)
+        } else {
+            None
+        };
+
+        if kind.is_none() && rand::thread_rng().gen_bool(CONSUMABLE_CHANCE) {
+            kind = Consumable::ALL
+                .choose(&mut rand::thread_rng())
+                .copied()
+                .map(BonusKind::Consumable);
+        }
+
+        if kind.is_none() && rand::thread_rng().gen_bool(AMMO_CHANCE) {
+            // prefer ammo which the player is able to use
+            kind = generate_ammo(find_player_weapon(world).unwrap_or_else(|| Arc::clone(&weapon)));
+        }
+
+        let kind = kind.unwrap_or(BonusKind::Weapon(weapon));
+        let bonus = spawn_bonus(world, self.position, kind.clone());
+
+        match kind {
+            BonusKind::Weapon(weapon) => {
+                spawn_image(world, bonus, &weapon);
+                spawn_label(world, bonus, weapon.name.clone());
+            }
+            BonusKind::Ammo { projectile, amount } => {
+                spawn_label(
+                    world,
+                    bonus,
+                    format!("{} ammo ({})", projectile.caliber, amount),
+                );
+            }
+            BonusKind::Attachment(attachment) => {
+                spawn_label(world, bonus, attachment.name().to_string());
+            }
+            BonusKind::Consumable(item) => {
+                spawn_label(world, bonus, item.name().to_string());
+            }
+        }
     }
 }
 
-fn spawn_bonus(world: &mut World, position: Vec2, weapon: &'static WeaponConfig) -> Entity {
+fn spawn_bonus(world: &mut World, position: Vec2, kind: BonusKind) -> Entity {
     let time = world.resource::<Time>().elapsed();
 
     return world
@@ -41,11 +89,11 @@ fn spawn_bonus(world: &mut World, position: Vec2, weapon: &'static WeaponConfig)
                 .with_scale(TRANSFORM_SCALE),
             ..Default::default()
         })
-        .insert(Bonus::new(weapon, time))
+        .insert(Bonus::new(kind, time))
         .id();
 }
 
-fn spawn_image(world: &mut World, bonus: Entity, weapon: &'static WeaponConfig) {
+fn spawn_image(world: &mut World, bonus: Entity, weapon: &WeaponConfig) {
     let texture = world
         .resource::<AssetServer>()
         .get_handle(weapon.get_image_path())
@@ -60,14 +108,14 @@ fn spawn_image(world: &mut World, bonus: Entity, weapon: &'static WeaponConfig)
         .set_parent(bonus);
 }
 
-fn spawn_label(world: &mut World, bonus: Entity, weapon: &'static WeaponConfig) {
+fn spawn_label(world: &mut World, bonus: Entity, label: String) {
     let font = world
         .resource::<AssetServer>()
         .get_handle(FONT_PATH)
         .unwrap_or_default();
 
     let text = Text::from_section(
-        weapon.name,
+        label,
         TextStyle {
             font,
             font_size: PIXELS_PER_METER,
@@ -86,8 +134,9 @@ fn spawn_label(world: &mut World, bonus: Entity, weapon: &'static WeaponConfig)
         .set_parent(bonus);
 }
 
-fn generate_weapon(level: u8) -> Option<&'static WeaponConfig> {
-    return WeaponConfig::ALL
+fn generate_weapon(weapons: &Registry<WeaponConfig>, level: u8) -> Option<Arc<WeaponConfig>> {
+    return weapons
+        .as_slice()
         .choose_weighted(&mut rand::thread_rng(), |weapon| {
             if weapon.level > level {
                 return 0.0;
@@ -95,5 +144,49 @@ fn generate_weapon(level: u8) -> Option<&'static WeaponConfig> {
                 return 1.0;
             }
         })
-        .ok();
+        .ok()
+        .cloned();
+}
+
+fn generate_ammo(weapon: Arc<WeaponConfig>) -> Option<BonusKind> {
+    let projectile = weapon.projectile.as_ref()?;
+    let amount = u16::from(weapon.ammo_capacity) * AMMO_MAGAZINES;
+
+    if amount == 0 {
+        return None;
+    }
+
+    return Some(BonusKind::Ammo {
+        projectile: Arc::clone(projectile),
+        amount,
+    });
+}
+
+/// Generates an attachment which fits the player's weapon
+fn generate_attachment(world: &mut World) -> Option<BonusKind> {
+    let attachments = world
+        .query_filtered::<&Weapon, With<Player>>()
+        .iter(world)
+        .next()
+        .map(|w| {
+            return w
+                .config
+                .attachments
+                .iter()
+                .filter(|a| w.can_attach(*a))
+                .collect::<Vec<WeaponAttachment>>();
+        })?;
+
+    return attachments
+        .choose(&mut rand::thread_rng())
+        .copied()
+        .map(BonusKind::Attachment);
+}
+
+fn find_player_weapon(world: &mut World) -> Option<Arc<WeaponConfig>> {
+    return world
+        .query_filtered::<&Weapon, With<Player>>()
+        .iter(world)
+        .next()
+        .map(|w| Arc::clone(&w.config));
 }
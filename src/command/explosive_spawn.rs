@@ -0,0 +1,49 @@
+use crate::{
+    component::{Explosive, ExplosiveConfig},
+    data::LAYER_PROJECTILE,
+    util::ext::Vec2Ext,
+};
+use bevy::{
+    ecs::system::Command,
+    prelude::{Color, Entity, Sprite, SpriteBundle, Time, Transform, Vec2, World},
+};
+use std::{f32::consts::FRAC_1_SQRT_2, sync::Arc};
+
+const SIZE: f32 = 0.12;
+const COLOR: Color = Color::rgb(0.24, 0.27, 0.16);
+const HEIGHT_INITIAL: f32 = 1.5;
+
+/// Throws an explosive at 45 degrees upwards. The fuse starts burning right away
+pub struct ExplosiveSpawn {
+    pub config: Arc<ExplosiveConfig>,
+    pub position: Vec2,
+    pub direction: f32,
+    pub velocity: f32,
+    pub thrower: Option<Entity>,
+}
+
+impl Command for ExplosiveSpawn {
+    fn apply(self, world: &mut World) {
+        let time = world.resource::<Time>().elapsed();
+        let velocity = self.velocity * FRAC_1_SQRT_2;
+
+        world
+            .spawn(SpriteBundle {
+                sprite: Sprite {
+                    color: COLOR,
+                    custom_size: Some(Vec2::splat(SIZE)),
+                    ..Default::default()
+                },
+                transform: Transform::from_translation(self.position.extend(LAYER_PROJECTILE)),
+                ..Default::default()
+            })
+            .insert(Explosive {
+                detonation_time: time + self.config.fuse,
+                config: self.config,
+                thrower: self.thrower,
+                velocity: Vec2::from_length(velocity, self.direction),
+                height: HEIGHT_INITIAL,
+                velocity_vertical: velocity,
+            });
+    }
+}
@@ -0,0 +1,168 @@
+use crate::{
+    command::ImpactSpawn,
+    component::{Actor, Beam, Collision, Obstacle, WeaponConfig},
+    data::LAYER_PROJECTILE,
+    material::LaserMaterial,
+    model::{geometry::GeometryProjection, Attack, AudioPlay, HitSource, HitZone, TransformLite},
+    resource::{AudioTracker, Cache, Config, Factions, HitResource},
+    util::{ext::Vec2Ext, math},
+};
+use bevy::{
+    asset::Assets,
+    ecs::system::Command,
+    math::{Quat, Vec3, Vec3Swizzles},
+    prelude::{Entity, Time, Transform, Vec2, World},
+    sprite::MaterialMesh2dBundle,
+};
+use std::{f32::consts::PI, sync::Arc};
+
+/// Resolves a hitscan shot instantly along a ray, stopping at the first actor or obstacle
+pub struct HitscanFire {
+    pub weapon: Arc<WeaponConfig>,
+    pub transform: TransformLite,
+    pub shooter: Option<Entity>,
+}
+
+impl Command for HitscanFire {
+    fn apply(self, world: &mut World) {
+        let Some(hitscan) = self.weapon.hitscan.as_ref() else {
+            log::warn!(
+                "Can't fire hitscan. Weapon {} isn't hitscan",
+                self.weapon.id
+            );
+            return;
+        };
+
+        let origin = self.transform.translation;
+        let direction = self.transform.direction;
+        let mut end = origin + Vec2::from_length(hitscan.range, direction);
+        let mut is_obstacle_hit = false;
+
+        for (obstacle, transform) in world.query::<(&Obstacle, &Transform)>().iter(world) {
+            if let Some(contact) = obstacle.intersect(transform.translation.xy(), &(origin, end)) {
+                end = contact.position;
+                is_obstacle_hit = true;
+            }
+        }
+
+        if let Some((target, target_position, contact, zone)) =
+            self.find_target(world, origin, end, direction)
+        {
+            let angle = math::angle_difference(direction, origin.angle_to(target_position));
+
+            world.resource_mut::<HitResource>().add(
+                target,
+                Vec2::from_length(hitscan.momentum, direction),
+                angle,
+                zone,
+                Attack {
+                    attacker: self.shooter,
+                    weapon: Some(Arc::clone(&self.weapon)),
+                    source: HitSource::Hitscan,
+                },
+            );
+
+            world.resource::<AudioTracker>().queue(AudioPlay {
+                path: "sounds/hit_body".into(),
+                volume: 1.0,
+                source: Some(target_position),
+                ..AudioPlay::DEFAULT
+            });
+
+            end = contact;
+        } else if is_obstacle_hit {
+            ImpactSpawn { position: end }.apply(world);
+        }
+
+        spawn_beam(world, origin, end);
+    }
+}
+
+impl HitscanFire {
+    /// Finds the closest actor crossed by the ray, with its position, the contact point and
+    /// the body zone hit
+    fn find_target(
+        &self,
+        world: &mut World,
+        origin: Vec2,
+        end: Vec2,
+        direction: f32,
+    ) -> Option<(Entity, Vec2, Vec2, HitZone)> {
+        let mut result: Option<(Entity, Vec2, Vec2, HitZone)> = None;
+        let mut result_distance = f32::MAX;
+        let segment = (origin, end);
+        let mut actors = world.query::<(Entity, &Collision, &Transform, &Actor)>();
+        let world: &World = world;
+        let friendly_fire = world.resource::<Config>().game.friendly_fire;
+        let factions = world.resource::<Factions>();
+        let shooter_actor = self.shooter.and_then(|e| world.get::<Actor>(e));
+
+        for (entity, collision, transform, actor) in actors.iter(world) {
+            if self.shooter == Some(entity) || friendly_fire.spares(factions, shooter_actor, actor)
+            {
+                continue;
+            }
+
+            let position = transform.translation.xy();
+            let projection = position.project_on(&segment);
+
+            if !position.is_close(projection, collision.radius) {
+                continue;
+            }
+
+            // step back from the projection to where the ray enters the body
+            let depth = f32::sqrt(
+                collision.radius * collision.radius - position.distance_squared(projection),
+            );
+
+            let contact = projection - Vec2::from_length(depth, direction);
+            let distance = origin.distance_squared(contact);
+
+            if distance < result_distance {
+                let zone = HitZone::find(
+                    projection,
+                    &TransformLite::from(transform),
+                    collision.radius,
+                );
+                result = Some((entity, position, contact, zone));
+                result_distance = distance;
+            }
+        }
+
+        return result;
+    }
+}
+
+fn spawn_beam(world: &mut World, origin: Vec2, end: Vec2) {
+    let cache = world.resource::<Cache>();
+
+    let Some(image) = cache.dummy_image.clone() else {
+        log::warn!("Failed to spawn a beam. The dummy image isn't initialized");
+        return;
+    };
+
+    let Some(mesh) = cache.dummy_mesh.clone() else {
+        log::warn!("Failed to spawn a beam. The dummy mesh isn't initialized");
+        return;
+    };
+
+    let time = world.resource::<Time>().elapsed();
+
+    let material = world
+        .resource_mut::<Assets<LaserMaterial>>()
+        .add(LaserMaterial { image });
+
+    world
+        .spawn(MaterialMesh2dBundle {
+            transform: Transform {
+                translation: ((origin + end) / 2.0).extend(LAYER_PROJECTILE),
+                scale: Vec3::new(origin.distance(end), Beam::THICKNESS, 1.0),
+                // the material fades out towards the mesh start, put it at the far end
+                rotation: Quat::from_rotation_z(origin.angle_to(end) + PI),
+            },
+            mesh: mesh.into(),
+            material,
+            ..Default::default()
+        })
+        .insert(Beam::new(time));
+}
@@ -0,0 +1,40 @@
+use super::blood_spawn::reserve_decal;
+use crate::data::LAYER_IMPACT;
+use bevy::{
+    ecs::{component::Component, system::Command},
+    prelude::{Color, Quat, Sprite, SpriteBundle, Transform, Vec2, World},
+};
+use rand::{thread_rng, Rng};
+use std::f32::consts::TAU;
+
+const SIZE: f32 = 0.08;
+const COLOR: Color = Color::rgba(0.16, 0.12, 0.08, 0.9);
+
+/// Bullet mark left on a static obstacle
+pub struct ImpactSpawn {
+    pub position: Vec2,
+}
+
+impl Command for ImpactSpawn {
+    fn apply(self, world: &mut World) {
+        if !reserve_decal::<ImpactComponent>(world) {
+            return;
+        }
+
+        world
+            .spawn(SpriteBundle {
+                sprite: Sprite {
+                    color: COLOR,
+                    custom_size: Some(Vec2::splat(SIZE)),
+                    ..Default::default()
+                },
+                transform: Transform::from_translation(self.position.extend(LAYER_IMPACT))
+                    .with_rotation(Quat::from_rotation_z(thread_rng().gen_range(0.0..TAU))),
+                ..Default::default()
+            })
+            .insert(ImpactComponent);
+    }
+}
+
+#[derive(Component)]
+struct ImpactComponent;
@@ -1,14 +1,10 @@
-use crate::{data::PIXELS_PER_METER, resource::Cache, LaserMaterial};
+use crate::{component::LaserSight, resource::Cache, LaserMaterial};
 use bevy::{
     asset::Assets,
     ecs::system::Command,
-    prelude::{BuildWorldChildren, Entity, Quat, Transform, Vec3, World},
+    prelude::{BuildWorldChildren, Entity, World},
     sprite::MaterialMesh2dBundle,
 };
-use std::f32::consts::PI;
-
-const LENGTH: f32 = 26.0 * PIXELS_PER_METER;
-const THICKNESS: f32 = 0.5 * PIXELS_PER_METER;
 
 pub struct LaserSightSet(pub Entity);
 
@@ -32,15 +28,12 @@ impl Command for LaserSightSet {
 
         world
             .spawn(MaterialMesh2dBundle {
-                transform: Transform {
-                    translation: Vec3::new(LENGTH / 2.0 + PIXELS_PER_METER / 2.0, 0.0, -1.0),
-                    scale: Vec3::new(LENGTH, THICKNESS, 1.0),
-                    rotation: Quat::from_rotation_z(PI),
-                },
+                transform: LaserSight::get_transform(LaserSight::LENGTH),
                 mesh: mesh.into(),
                 material,
                 ..Default::default()
             })
+            .insert(LaserSight)
             .set_parent(self.0);
     }
 }
@@ -3,20 +3,30 @@ mod actor_melee_reset;
 mod actor_player_set;
 mod actor_release;
 mod actor_set;
+mod ammo_add;
 mod blood_spawn;
 mod bonus_activate;
 mod bonus_spawn;
 mod cursor_grab;
 mod exit;
+mod explosive_spawn;
+mod hitscan_fire;
+mod impact_spawn;
 mod laser_sight_set;
+mod navigation_init;
 mod notify;
 mod projectile_spawn;
+mod scorch_spawn;
 mod status_bar_set;
 mod terrain_init;
+mod weapon_attach;
 mod weapon_set;
+mod weapon_switch;
 
 pub use self::{
     actor_bot_set::*, actor_melee_reset::*, actor_player_set::*, actor_release::*, actor_set::*,
-    blood_spawn::*, bonus_activate::*, bonus_spawn::*, cursor_grab::*, exit::*, laser_sight_set::*,
-    notify::*, projectile_spawn::*, status_bar_set::*, terrain_init::*, weapon_set::*,
+    ammo_add::*, blood_spawn::*, bonus_activate::*, bonus_spawn::*, cursor_grab::*, exit::*,
+    explosive_spawn::*, hitscan_fire::*, impact_spawn::*, laser_sight_set::*, navigation_init::*,
+    notify::*, projectile_spawn::*, scorch_spawn::*, status_bar_set::*, terrain_init::*,
+    weapon_attach::*, weapon_set::*, weapon_switch::*,
 };
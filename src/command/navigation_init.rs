@@ -0,0 +1,21 @@
+use crate::{component::Obstacle, data::WORLD_SIZE, model::NavGrid, resource::Navigation};
+use bevy::{
+    ecs::system::Command,
+    math::Vec3Swizzles,
+    prelude::{Transform, World},
+};
+
+/// Builds the navigation grid from static obstacles. Should go after the level is spawned
+pub struct NavigationInit;
+
+impl Command for NavigationInit {
+    fn apply(self, world: &mut World) {
+        let mut grid = NavGrid::new(WORLD_SIZE);
+
+        for (obstacle, transform) in world.query::<(&Obstacle, &Transform)>().iter(world) {
+            grid.block(obstacle, transform.translation.xy());
+        }
+
+        world.resource_mut::<Navigation>().set_grid(grid);
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{
-    component::{Projectile, ProjectileConfig},
+    component::{Projectile, ProjectileConfig, WeaponConfig},
     data::LAYER_PROJECTILE,
     material::ProjectileMaterial,
     model::TransformLite,
@@ -13,12 +13,14 @@ use bevy::{
     prelude::{Entity, Time, Transform, Vec2, World},
     sprite::MaterialMesh2dBundle,
 };
+use std::sync::Arc;
 
 pub struct ProjectileSpawn {
-    pub config: &'static ProjectileConfig,
+    pub config: Arc<ProjectileConfig>,
     pub transform: TransformLite,
     pub velocity: f32,
     pub shooter: Option<Entity>,
+    pub weapon: Option<Arc<WeaponConfig>>,
 }
 
 impl Command for ProjectileSpawn {
@@ -41,6 +43,7 @@ impl Command for ProjectileSpawn {
             self.transform.translation,
             Vec2::from_length(self.velocity, self.transform.direction),
             self.shooter,
+            self.weapon,
         );
 
         let material = world
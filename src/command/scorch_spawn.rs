@@ -0,0 +1,40 @@
+use super::blood_spawn::reserve_decal;
+use crate::data::LAYER_BLUFF;
+use bevy::{
+    ecs::{component::Component, system::Command},
+    prelude::{Color, Quat, Sprite, SpriteBundle, Transform, Vec2, World},
+};
+use rand::{thread_rng, Rng};
+use std::f32::consts::TAU;
+
+const COLOR: Color = Color::rgba(0.05, 0.04, 0.03, 0.6);
+
+/// Burnt ground left by an explosion
+pub struct ScorchSpawn {
+    pub position: Vec2,
+    pub size: f32,
+}
+
+impl Command for ScorchSpawn {
+    fn apply(self, world: &mut World) {
+        if !reserve_decal::<ScorchComponent>(world) {
+            return;
+        }
+
+        world
+            .spawn(SpriteBundle {
+                sprite: Sprite {
+                    color: COLOR,
+                    custom_size: Some(Vec2::splat(self.size)),
+                    ..Default::default()
+                },
+                transform: Transform::from_translation(self.position.extend(LAYER_BLUFF))
+                    .with_rotation(Quat::from_rotation_z(thread_rng().gen_range(0.0..TAU))),
+                ..Default::default()
+            })
+            .insert(ScorchComponent);
+    }
+}
+
+#[derive(Component)]
+struct ScorchComponent;
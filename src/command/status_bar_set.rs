@@ -30,6 +30,8 @@ impl Command for StatusBarSet {
                 ammo: 1.0,
                 ammo_alpha: 0.0,
                 stamina: 0.0,
+                armor: 0.0,
+                armor_alpha: 0.0,
                 image,
             });
 
@@ -0,0 +1,60 @@
+use super::{
+    weapon_set::{equip_weapon, unequip_weapon},
+    Notify,
+};
+use crate::{
+    component::{Player, WeaponAttachment},
+    model::AudioPlay,
+    resource::AudioTracker,
+};
+use bevy::{
+    ecs::system::Command,
+    math::Vec3Swizzles,
+    prelude::{Entity, Transform, World},
+};
+
+/// Mounts an attachment on the weapon in actor's hands if the weapon supports it
+pub struct WeaponAttach {
+    pub entity: Entity,
+    pub attachment: WeaponAttachment,
+}
+
+impl WeaponAttach {
+    fn play_attach_sound(&self, world: &mut World) {
+        if let Some(source) = world
+            .get::<Transform>(self.entity)
+            .map(|t| t.translation.xy())
+        {
+            world.resource_mut::<AudioTracker>().queue(AudioPlay {
+                path: "sounds/pickup_weapon".into(),
+                volume: 0.6,
+                source: Some(source),
+                ..AudioPlay::DEFAULT
+            });
+        }
+    }
+}
+
+impl Command for WeaponAttach {
+    fn apply(self, world: &mut World) {
+        let Some(mut weapon) = unequip_weapon(world, self.entity) else {
+            return;
+        };
+
+        let is_attached = weapon.attach(self.attachment);
+        let weapon_name = weapon.config.name.clone();
+
+        // equip again to update the sprite and the actor mass
+        equip_weapon(world, self.entity, weapon);
+
+        if is_attached && world.get::<Player>(self.entity).is_some() {
+            self.play_attach_sound(world);
+
+            Notify {
+                text_small: format!("{} mounted on {}", self.attachment.name(), weapon_name).into(),
+                ..Default::default()
+            }
+            .apply(world);
+        }
+    }
+}
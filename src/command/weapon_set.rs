@@ -1,117 +1,91 @@
+use super::{LaserSightSet, Notify};
 use crate::{
-    component::{Actor, ActorWeaponSprite, Inertia, Player, Weapon, WeaponConfig, WeaponGrip},
+    component::{
+        Actor, ActorWeaponSprite, Inertia, LaserSight, Player, Weapon, WeaponAttachment,
+        WeaponConfig, WeaponGrip, WeaponInventory,
+    },
     data::PIXELS_PER_METER,
     model::AudioPlay,
-    resource::AudioTracker,
+    resource::{AudioTracker, Config, GameMode, Registry},
+    util::SmartString,
 };
 use bevy::{
-    asset::{AssetServer, Assets, Handle},
-    ecs::system::Command,
+    asset::{AssetServer, Assets},
+    ecs::{component::Component, system::Command},
     math::Vec3Swizzles,
-    prelude::{BuildWorldChildren, Children, DespawnRecursiveExt, Entity, Transform, Vec2, World},
+    prelude::{
+        BuildWorldChildren, Children, Color, DespawnRecursiveExt, Entity, Transform, Vec2, World,
+    },
     render::texture::Image,
     sprite::{Anchor, Sprite, SpriteBundle},
 };
+use std::sync::Arc;
 
 const WEAPON_MASS_MULTIPLAYER: f32 = 5.0;
 
 pub struct WeaponSet {
     pub entity: Entity,
-    pub weapon: Option<&'static WeaponConfig>,
+    /// Weapon id from the weapons registry. None to disarm
+    pub weapon: Option<SmartString<'static>>,
 }
 
 impl WeaponSet {
-    fn remove_old_weapon_sprite(&self, world: &mut World) {
-        let mut to_remove = Vec::new();
-
-        if let Some(children) = world.get::<Children>(self.entity) {
-            for &child in children {
-                if world.get::<ActorWeaponSprite>(child).is_some() {
-                    to_remove.push(child);
-                }
-            }
-        }
-
-        world.entity_mut(self.entity).remove_children(&to_remove);
-
-        for entity in &to_remove {
-            world.entity_mut(*entity).despawn_recursive();
-        }
-    }
+    fn find_weapon(&self, world: &World) -> Result<Option<Arc<WeaponConfig>>, ()> {
+        let Some(id) = &self.weapon else {
+            return Ok(None);
+        };
 
-    fn remove_old_weapon_component(&self, world: &mut World) {
-        if let Some(weapon) = world.get::<Weapon>(self.entity) {
-            let weapon_mass = weapon.config.get_mass_with_full_ammo();
-            self.update_actor_mass(world, -weapon_mass);
-            world.entity_mut(self.entity).remove::<Weapon>();
+        if let Some(weapon) = world.resource::<Registry<WeaponConfig>>().get(id.as_ref()) {
+            return Ok(Some(Arc::clone(weapon)));
+        } else {
+            log::warn!("Can't set weapon. Weapon {} not found", id.as_ref());
+            return Err(());
         }
     }
 
-    fn spawn_weapon_sprite(&self, world: &mut World, weapon: &WeaponConfig) {
-        let image = world
-            .resource::<AssetServer>()
-            .get_handle(weapon.get_image_path())
-            .unwrap_or_default();
-
-        let anchor = Self::find_image_anchor(world, weapon, &image);
-
-        world
-            .spawn(SpriteBundle {
-                sprite: Sprite {
-                    anchor,
-                    ..Default::default()
-                },
-                texture: image,
-                transform: Transform::from_xyz(0.0, 0.0, -0.1),
-                ..Default::default()
-            })
-            .insert(ActorWeaponSprite)
-            .set_parent(self.entity);
-    }
-
-    fn find_image_anchor(world: &World, weapon: &WeaponConfig, image: &Handle<Image>) -> Anchor {
-        let arms_length = if let WeaponGrip::OneHand = weapon.grip {
-            Actor::ARMS_LENGTH_1
-        } else {
-            Actor::ARMS_LENGTH_2
+    /// Puts the weapon into the inventory if it takes another slot than the weapon in hands.
+    /// Returns the weapon it has replaced, if any
+    fn try_holster(&self, world: &mut World, weapon: Weapon) -> Result<Option<Weapon>, Weapon> {
+        let Some(mut inventory) = world.get_mut::<WeaponInventory>(self.entity) else {
+            return Err(weapon);
         };
 
-        if let Some(image) = world.resource::<Assets<Image>>().get(image) {
-            let offset = weapon.image_offset - arms_length * PIXELS_PER_METER;
-            return Anchor::Custom(Vec2::new(
-                offset / image.texture_descriptor.size.width as f32 - 0.5,
-                0.0,
-            ));
-        } else {
-            log::warn!(
-                "Unable to set anchor for image {} since it hasn't loaded yet",
-                weapon.get_image_path(),
-            );
+        let slot = WeaponInventory::get_slot(&weapon.config);
 
-            return Anchor::default();
+        if inventory.get_active().map_or(true, |active| active == slot) {
+            return Err(weapon);
         }
-    }
 
-    fn update_actor_weapon(&self, world: &mut World, weapon: &'static WeaponConfig) {
-        world.entity_mut(self.entity).insert(Weapon::new(weapon));
+        let replaced = inventory.holster(weapon);
+        inventory.request(slot);
+
+        return Ok(replaced);
     }
 
-    fn update_actor_image(&self, world: &mut World, weapon: &WeaponConfig) {
-        if let Some(actor) = world.get::<Actor>(self.entity) {
-            let image_suffix = weapon.grip.actor_image_suffix();
-            let image_path = actor.config.get_image_path(image_suffix);
-            let image = world
-                .resource::<AssetServer>()
-                .get_handle::<Image>(image_path)
-                .unwrap_or_default();
+    /// Creates a new weapon. The laser sight game mode mounts sights on every eligible weapon
+    fn create_weapon(world: &World, config: Arc<WeaponConfig>) -> Weapon {
+        let mut weapon = Weapon::new(config);
 
-            world.entity_mut(self.entity).insert(image);
+        if world
+            .resource::<Config>()
+            .game
+            .modes
+            .contains(&GameMode::LaserSight)
+        {
+            weapon.attach(WeaponAttachment::LaserSight);
         }
+
+        return weapon;
     }
 
-    fn update_actor_mass(&self, world: &mut World, change: f32) {
-        if let Some(inertia) = world.get_mut::<Inertia>(self.entity).as_mut() {
-            inertia.mass += change * WEAPON_MASS_MULTIPLAYER;
+    /// Lets the player know that the weapon has been thrown away to make room for the new one
+    fn notify_replaced(world: &mut World, replaced: &Weapon, picked: &WeaponConfig) {
+        if replaced.config.id != picked.id {
+            Notify {
+                text_small: format!("{} dropped", replaced.config.name).into(),
+                ..Default::default()
+            }
+            .apply(world);
         }
     }
 
@@ -132,18 +106,194 @@ impl WeaponSet {
 
 impl Command for WeaponSet {
     fn apply(self, world: &mut World) {
-        self.remove_old_weapon_component(world);
-        self.remove_old_weapon_sprite(world);
+        let Ok(weapon) = self.find_weapon(world) else {
+            return;
+        };
+
+        let Some(weapon) = weapon else {
+            if let Some(mut inventory) = world.get_mut::<WeaponInventory>(self.entity) {
+                inventory.clear();
+            }
+
+            unequip_weapon(world, self.entity);
+            return;
+        };
+
+        let config = Arc::clone(&weapon);
+        let weapon = Self::create_weapon(world, weapon);
+
+        let replaced = match self.try_holster(world, weapon) {
+            Ok(replaced) => replaced,
+            Err(weapon) => {
+                let slot = WeaponInventory::get_slot(&weapon.config);
+                let replaced = unequip_weapon(world, self.entity);
+                equip_weapon(world, self.entity, weapon);
+
+                if let Some(mut inventory) = world.get_mut::<WeaponInventory>(self.entity) {
+                    inventory.set_active(Some(slot));
+                }
+
+                replaced
+            }
+        };
+
+        if world.get::<Player>(self.entity).is_some() {
+            if let Some(replaced) = replaced {
+                Self::notify_replaced(world, &replaced, &config);
+            }
+
+            self.play_pickup_sound(world);
+        }
+    }
+}
+
+/// Puts the weapon into actor's hands. The hands must be empty
+pub(super) fn equip_weapon(world: &mut World, entity: Entity, weapon: Weapon) {
+    spawn_weapon_sprite(world, entity, &weapon);
+
+    if weapon.has_attachment(WeaponAttachment::LaserSight) {
+        LaserSightSet(entity).apply(world);
+    }
 
-        if let Some(weapon) = self.weapon {
-            self.spawn_weapon_sprite(world, weapon);
-            self.update_actor_weapon(world, weapon);
-            self.update_actor_image(world, weapon);
-            self.update_actor_mass(world, weapon.get_mass_with_full_ammo());
+    update_actor_image(world, entity, weapon.config.grip.actor_image_suffix());
+    update_actor_mass(world, entity, weapon.get_mass_with_full_ammo());
+    world.entity_mut(entity).insert(weapon);
+}
 
-            if world.get::<Player>(self.entity).is_some() {
-                self.play_pickup_sound(world);
+/// Takes the weapon out of actor's hands
+pub(super) fn unequip_weapon(world: &mut World, entity: Entity) -> Option<Weapon> {
+    remove_children_with::<ActorWeaponSprite>(world, entity);
+    remove_children_with::<LaserSight>(world, entity);
+    let weapon = world.entity_mut(entity).take::<Weapon>()?;
+    update_actor_image(world, entity, 0);
+    update_actor_mass(world, entity, -weapon.get_mass_with_full_ammo());
+    return Some(weapon);
+}
+
+fn remove_children_with<T: Component>(world: &mut World, entity: Entity) {
+    let mut to_remove = Vec::new();
+
+    if let Some(children) = world.get::<Children>(entity) {
+        for &child in children {
+            if world.get::<T>(child).is_some() {
+                to_remove.push(child);
             }
         }
     }
+
+    world.entity_mut(entity).remove_children(&to_remove);
+
+    for entity in &to_remove {
+        world.entity_mut(*entity).despawn_recursive();
+    }
+}
+
+fn spawn_weapon_sprite(world: &mut World, entity: Entity, weapon: &Weapon) {
+    let config = &weapon.config;
+    let image = world
+        .resource::<AssetServer>()
+        .get_handle(config.get_image_path())
+        .unwrap_or_default();
+
+    let image_width = world
+        .resource::<Assets<Image>>()
+        .get(&image)
+        .map(|i| i.texture_descriptor.size.width as f32);
+
+    let anchor = find_image_anchor(config, image_width);
+
+    let sprite = world
+        .spawn(SpriteBundle {
+            sprite: Sprite {
+                anchor,
+                ..Default::default()
+            },
+            texture: image,
+            transform: Transform::from_xyz(0.0, 0.0, -0.1),
+            ..Default::default()
+        })
+        .insert(ActorWeaponSprite)
+        .set_parent(entity)
+        .id();
+
+    // without the image size there's no way to find the muzzle
+    if let Some(image_width) = image_width {
+        for attachment in weapon.get_attachments() {
+            spawn_attachment_sprite(world, sprite, config, image_width, attachment);
+        }
+    }
+}
+
+/// Draws the attachment as a plain rectangle over the weapon image
+fn spawn_attachment_sprite(
+    world: &mut World,
+    weapon_sprite: Entity,
+    weapon: &WeaponConfig,
+    image_width: f32,
+    attachment: WeaponAttachment,
+) {
+    let grip = get_arms_length(weapon) * PIXELS_PER_METER;
+    let muzzle = grip + image_width - weapon.image_offset;
+
+    // position and size are in pixels, z is relative to the weapon image
+    let (position, size, z) = match attachment {
+        WeaponAttachment::Suppressor => (Vec2::new(muzzle + 3.0, 0.0), Vec2::new(6.0, 2.0), 0.01),
+        WeaponAttachment::Scope => (Vec2::new(grip + 3.0, 0.0), Vec2::new(5.0, 1.5), 0.01),
+        WeaponAttachment::ExtendedMagazine => {
+            (Vec2::new(grip + 4.0, -1.5), Vec2::new(2.0, 4.0), -0.01)
+        }
+        WeaponAttachment::LaserSight => (Vec2::new(grip + 7.0, -1.0), Vec2::new(3.0, 1.0), 0.01),
+    };
+
+    world
+        .spawn(SpriteBundle {
+            sprite: Sprite {
+                color: Color::rgb(0.15, 0.15, 0.15),
+                custom_size: Some(size),
+                ..Default::default()
+            },
+            transform: Transform::from_xyz(position.x, position.y, z),
+            ..Default::default()
+        })
+        .set_parent(weapon_sprite);
+}
+
+fn find_image_anchor(weapon: &WeaponConfig, image_width: Option<f32>) -> Anchor {
+    if let Some(image_width) = image_width {
+        let offset = weapon.image_offset - get_arms_length(weapon) * PIXELS_PER_METER;
+        return Anchor::Custom(Vec2::new(offset / image_width - 0.5, 0.0));
+    } else {
+        log::warn!(
+            "Unable to set anchor for image {} since it hasn't loaded yet",
+            weapon.get_image_path(),
+        );
+
+        return Anchor::default();
+    }
+}
+
+fn get_arms_length(weapon: &WeaponConfig) -> f32 {
+    if let WeaponGrip::OneHand = weapon.grip {
+        return Actor::ARMS_LENGTH_1;
+    } else {
+        return Actor::ARMS_LENGTH_2;
+    }
+}
+
+fn update_actor_image(world: &mut World, entity: Entity, image_suffix: u8) {
+    if let Some(actor) = world.get::<Actor>(entity) {
+        let image_path = actor.config.get_image_path(image_suffix);
+        let image = world
+            .resource::<AssetServer>()
+            .get_handle::<Image>(image_path)
+            .unwrap_or_default();
+
+        world.entity_mut(entity).insert(image);
+    }
+}
+
+fn update_actor_mass(world: &mut World, entity: Entity, change: f32) {
+    if let Some(inertia) = world.get_mut::<Inertia>(entity).as_mut() {
+        inertia.mass += change * WEAPON_MASS_MULTIPLAYER;
+    }
 }
@@ -0,0 +1,34 @@
+use super::weapon_set::{equip_weapon, unequip_weapon};
+use crate::component::WeaponInventory;
+use bevy::{
+    ecs::system::Command,
+    prelude::{Entity, World},
+    time::Time,
+};
+
+/// Holsters the weapon in hands and draws the one from the given inventory slot
+pub struct WeaponSwitch {
+    pub entity: Entity,
+    pub slot: usize,
+}
+
+impl Command for WeaponSwitch {
+    fn apply(self, world: &mut World) {
+        let time = world.resource::<Time>().elapsed();
+        let holstered = unequip_weapon(world, self.entity);
+
+        let Some(mut inventory) = world.get_mut::<WeaponInventory>(self.entity) else {
+            return;
+        };
+
+        if let Some(mut weapon) = holstered {
+            weapon.holster();
+            inventory.holster(weapon);
+        }
+
+        if let Some(mut weapon) = inventory.draw(self.slot) {
+            weapon.draw(time, weapon.config.get_switching_time());
+            equip_weapon(world, self.entity, weapon);
+        }
+    }
+}
@@ -1,35 +1,47 @@
 use crate::{
-    data::BotConfig,
-    model::{ActorActions, ActorActionsExt},
+    data::{deserialize_seconds, BotConfig},
+    model::{ActorActions, ActorActionsExt, Faction, HitZone},
     util::ext::{DurationExt, Vec2Ext},
 };
 use bevy::{ecs::component::Component, math::Vec2};
-use std::{f32::consts::TAU, time::Duration};
+use serde::Deserialize;
+use std::{sync::Arc, time::Duration};
 
 #[derive(Component)]
 pub struct Actor {
-    pub config: &'static ActorConfig,
+    pub config: Arc<ActorConfig>,
+    pub faction: Faction,
     pub skill: f32,
     pub stamina: f32,
     pub movement: Vec2,
     pub actions: ActorActions,
     pub look_at: Option<f32>,
     pub melee_next: Duration,
+    /// Arm injury from 0.0 to 1.0. Worsens aim and reloading
+    pub arm_wound: f32,
+    /// Leg injury from 0.0 to 1.0. Slows movement
+    pub leg_wound: f32,
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// What the actor is made of. Sides are decided by factions
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum ActorKind {
     Human,
     Zombie,
 }
 
+#[derive(Deserialize)]
 pub struct ActorConfig {
+    /// Also the assets directory under `actors/`
+    pub id: String,
     pub kind: ActorKind,
-    pub name: &'static str,
+    pub faction: Faction,
     // movement
     pub movement_velocity: f32,
     pub rotation_velocity: f32,
     pub sprint_factor: f32,
+    #[serde(deserialize_with = "deserialize_seconds")]
     pub stamina: Duration,
     // health
     pub health: f32,
@@ -41,21 +53,40 @@ pub struct ActorConfig {
     pub melee_damage: f32,
     pub melee_distance: f32,
     pub melee_distance_angular: f32,
+    #[serde(deserialize_with = "deserialize_seconds")]
     pub melee_interval: Duration,
+    /// Momentum of a head hit which kills instantly. None if head hits aren't special
+    #[serde(default)]
+    pub headshot_threshold: Option<f32>,
     // shooting
     pub reloading_speed: f32,
     pub recoil_factor: f32,
+    /// Explosive id the actor carries, if any
+    #[serde(default)]
+    pub grenade: Option<String>,
+    #[serde(default)]
+    pub grenades: u8,
+    /// Armor id the actor wears, if any
+    #[serde(default)]
+    pub armor: Option<String>,
     // misc
-    pub bot: &'static BotConfig,
-    pub images: &'static [u8],
+    pub bot: BotConfig,
+    pub images: Vec<u8>,
 }
 
 impl Actor {
     pub const ARMS_LENGTH_1: f32 = 0.546875;
     pub const ARMS_LENGTH_2: f32 = 0.34375;
-
-    pub const fn new(config: &'static ActorConfig, skill: f32) -> Self {
+    pub const AIM_MOVEMENT_FACTOR: f32 = 0.5;
+    pub const CROUCH_MOVEMENT_FACTOR: f32 = 0.4;
+    pub const CROUCH_NOISE_FACTOR: f32 = 0.3;
+    pub const LEG_WOUND_SLOWDOWN: f32 = 0.6;
+    pub const ARM_WOUND_DEVIATION: f32 = 2.0;
+    pub const ARM_WOUND_RELOADING: f32 = 1.0;
+
+    pub fn new(config: Arc<ActorConfig>, skill: f32) -> Self {
         return Self {
+            faction: config.faction,
             config,
             skill,
             stamina: 1.0,
@@ -63,9 +94,26 @@ impl Actor {
             actions: ActorActions::EMPTY,
             look_at: None,
             melee_next: Duration::ZERO,
+            arm_wound: 0.0,
+            leg_wound: 0.0,
         };
     }
 
+    /// Injures a limb if the hit zone is one
+    pub fn wound(&mut self, zone: HitZone, damage: f32) {
+        let wound = damage / self.config.health;
+
+        match zone {
+            HitZone::Arm => {
+                self.arm_wound = f32::min(self.arm_wound + wound, 1.0);
+            }
+            HitZone::Leg => {
+                self.leg_wound = f32::min(self.leg_wound + wound, 1.0);
+            }
+            HitZone::Head | HitZone::Torso => {}
+        }
+    }
+
     pub fn reset_actions(&mut self) {
         self.movement = Vec2::ZERO;
         self.actions = ActorActions::EMPTY;
@@ -90,67 +138,8 @@ impl Actor {
 }
 
 impl ActorConfig {
-    const HUMAN_HEALTH: f32 = 9.0;
-
-    pub const HUMAN: Self = Self {
-        kind: ActorKind::Human,
-        name: "human",
-        movement_velocity: 2.8,
-        rotation_velocity: 3.5,
-        sprint_factor: 1.6,
-        stamina: Duration::from_secs(16),
-        health: Self::HUMAN_HEALTH,
-        pain_threshold: 0.02,
-        radius: 0.25,
-        mass: 85.0,
-        melee_damage: Self::HUMAN_HEALTH / 16.0,
-        melee_distance: 0.7,
-        melee_distance_angular: TAU / 5.0,
-        melee_interval: Duration::from_millis(600),
-        reloading_speed: 0.6,
-        recoil_factor: 1.0,
-        bot: BotConfig::HUMAN,
-        images: &[1, 2],
-    };
-
-    pub const ZOMBIE: Self = Self {
-        kind: ActorKind::Zombie,
-        name: "zombie",
-        movement_velocity: Self::HUMAN.movement_velocity * 0.33,
-        rotation_velocity: Self::HUMAN.rotation_velocity * 0.4,
-        sprint_factor: Self::HUMAN.sprint_factor,
-        stamina: Duration::from_secs(10),
-        health: Self::HUMAN.health * 0.6,
-        pain_threshold: f32::INFINITY, // disabled
-        radius: 0.21,
-        mass: 70.0,
-        melee_damage: Self::HUMAN.health / 12.0,
-        melee_distance: Self::HUMAN.melee_distance,
-        melee_distance_angular: Self::HUMAN.melee_distance_angular,
-        melee_interval: Self::HUMAN.melee_interval,
-        reloading_speed: Self::HUMAN.reloading_speed * 2.0,
-        recoil_factor: 6.0,
-        bot: BotConfig::ZOMBIE,
-        images: &[0, 1, 2],
-    };
-
-    pub const ZOMBIE_AGILE: Self = Self {
-        kind: ActorKind::Zombie,
-        name: "zombie_agile",
-        movement_velocity: Self::HUMAN.movement_velocity * 0.8,
-        rotation_velocity: 4.0,
-        stamina: Duration::from_secs(60),
-        health: Self::ZOMBIE.health / 2.0,
-        radius: 0.19,
-        mass: 45.0,
-        melee_damage: Self::ZOMBIE.melee_damage / 2.0,
-        bot: BotConfig::ZOMBIE_AGILE,
-        images: &[0],
-        ..Self::ZOMBIE
-    };
-
     pub fn get_assets_path(&self) -> String {
-        return format!("actors/{}", self.name);
+        return format!("actors/{}", self.id);
     }
 
     pub fn get_image_path(&self, mut suffix: u8) -> String {
@@ -0,0 +1,32 @@
+use bevy::ecs::component::Component;
+use std::collections::HashMap;
+
+/// Spare ammunition carried by an actor, keyed by projectile caliber.
+/// Actors without this component have unlimited ammo
+#[derive(Component, Default)]
+pub struct AmmoReserve {
+    rounds: HashMap<String, u16>,
+}
+
+impl AmmoReserve {
+    pub fn add(&mut self, caliber: &str, amount: u16) {
+        let rounds = self.rounds.entry(caliber.to_string()).or_default();
+        *rounds = rounds.saturating_add(amount);
+    }
+
+    /// Takes up to `amount` rounds and returns how many were actually taken
+    pub fn take(&mut self, caliber: &str, amount: u8) -> u8 {
+        let Some(rounds) = self.rounds.get_mut(caliber) else {
+            return 0;
+        };
+
+        let taken = u16::min(*rounds, u16::from(amount));
+        *rounds -= taken;
+
+        return u8::try_from(taken).unwrap_or(amount);
+    }
+
+    pub fn get(&self, caliber: &str) -> u16 {
+        return self.rounds.get(caliber).copied().unwrap_or(0);
+    }
+}
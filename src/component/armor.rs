@@ -0,0 +1,156 @@
+use super::ProjectileConfig;
+use crate::{
+    model::{HitZone, HitZones},
+    util::math::interpolate,
+};
+use bevy::ecs::component::Component;
+use std::sync::Arc;
+
+/// Protective equipment which stops weak projectiles and wears out
+#[derive(Component)]
+pub struct Armor {
+    pub config: Arc<ArmorConfig>,
+    durability: f32,
+}
+
+pub struct ArmorConfig {
+    /// Shown when the armor breaks
+    pub name: String,
+    /// Each class stops one more unit of projectile momentum
+    pub class: u8,
+    pub durability: f32,
+    pub coverage: HitZones,
+}
+
+impl ArmorConfig {
+    const CLASS_MOMENTUM: f32 = 1.0;
+
+    /// Momentum of projectiles which the intact armor stops
+    pub fn get_threshold(&self) -> f32 {
+        return f32::from(self.class) * Self::CLASS_MOMENTUM;
+    }
+}
+
+impl Armor {
+    /// Share of a stopped projectile momentum still hurting the body
+    const BLUNT_DAMAGE: f32 = 0.2;
+    /// Share of a piercing projectile momentum wearing the armor
+    const PIERCED_WEAR: f32 = 0.5;
+    /// Threshold share left when the armor is almost worn out
+    const WORN_THRESHOLD: f32 = 0.5;
+
+    pub fn new(config: Arc<ArmorConfig>) -> Self {
+        return Self {
+            durability: config.durability,
+            config,
+        };
+    }
+
+    /// Takes a hit to the zone. Returns the momentum which reaches the body.
+    /// Only projectiles are stopped, blows and blasts pass as is
+    pub fn absorb(
+        &mut self,
+        zone: HitZone,
+        momentum: f32,
+        projectile: Option<&ProjectileConfig>,
+    ) -> f32 {
+        let Some(projectile) = projectile else {
+            return momentum;
+        };
+
+        if !self.config.coverage.contains(zone) || self.is_broken() {
+            return momentum;
+        }
+
+        let threshold = self.config.get_threshold()
+            * interpolate(Self::WORN_THRESHOLD, 1.0, self.get_durability_normalized());
+
+        if momentum * (1.0 + projectile.armor_piercing) < threshold {
+            self.durability = f32::max(self.durability - momentum, 0.0);
+            return momentum * Self::BLUNT_DAMAGE;
+        } else {
+            self.durability = f32::max(self.durability - momentum * Self::PIERCED_WEAR, 0.0);
+            return momentum;
+        }
+    }
+
+    pub fn get_durability_normalized(&self) -> f32 {
+        return self.durability / self.config.durability;
+    }
+
+    pub fn is_broken(&self) -> bool {
+        return self.durability <= 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vest() -> Armor {
+        return Armor::new(Arc::new(ArmorConfig {
+            name: "Vest".to_string(),
+            class: 3,
+            durability: 20.0,
+            coverage: HitZone::Torso.into(),
+        }));
+    }
+
+    fn projectile(armor_piercing: f32) -> ProjectileConfig {
+        return ProjectileConfig {
+            caliber: "test".to_string(),
+            fragments: 1,
+            mass: 0.01,
+            size: 1.0,
+            penetration: 0.0,
+            armor_piercing,
+        };
+    }
+
+    #[test]
+    fn test_stops_weak() {
+        let mut armor = vest();
+        let damage = armor.absorb(HitZone::Torso, 2.0, Some(&projectile(0.0)));
+        assert!(damage < 2.0);
+        assert!(armor.get_durability_normalized() < 1.0);
+    }
+
+    #[test]
+    fn test_passes_strong() {
+        let mut armor = vest();
+        assert_eq!(
+            armor.absorb(HitZone::Torso, 8.0, Some(&projectile(0.3))),
+            8.0
+        );
+        assert_eq!(
+            armor.absorb(HitZone::Torso, 2.5, Some(&projectile(0.3))),
+            2.5
+        );
+    }
+
+    #[test]
+    fn test_uncovered_and_blunt() {
+        let mut armor = vest();
+        assert_eq!(
+            armor.absorb(HitZone::Head, 1.0, Some(&projectile(0.0))),
+            1.0
+        );
+        assert_eq!(armor.absorb(HitZone::Torso, 1.0, None), 1.0);
+        assert_eq!(armor.get_durability_normalized(), 1.0);
+    }
+
+    #[test]
+    fn test_wears_out() {
+        let mut armor = vest();
+
+        for _ in 0..20 {
+            armor.absorb(HitZone::Torso, 2.0, Some(&projectile(0.0)));
+        }
+
+        assert_eq!(armor.get_durability_normalized(), 0.0);
+        assert_eq!(
+            armor.absorb(HitZone::Torso, 2.0, Some(&projectile(0.0))),
+            2.0
+        );
+    }
+}
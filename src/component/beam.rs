@@ -0,0 +1,14 @@
+use bevy::ecs::component::Component;
+use derive_more::Constructor;
+use std::time::Duration;
+
+/// Short-lived visual of a hitscan shot
+#[derive(Component, Constructor)]
+pub struct Beam {
+    pub spawned: Duration,
+}
+
+impl Beam {
+    pub const DURATION: Duration = Duration::from_millis(120);
+    pub const THICKNESS: f32 = 0.25;
+}
@@ -1,10 +1,10 @@
-use super::WeaponConfig;
+use super::{Consumable, ProjectileConfig, WeaponAttachment, WeaponConfig};
 use bevy::ecs::component::Component;
-use std::time::Duration;
+use std::{sync::Arc, time::Duration};
 
 #[derive(Component)]
 pub struct Bonus {
-    pub weapon: &'static WeaponConfig,
+    pub kind: BonusKind,
     expiration: Duration,
 }
 
@@ -15,9 +15,9 @@ impl Bonus {
     pub const TEXT_SCALE_MAX: f32 = 0.41;
     pub const LIFETIME: Duration = Duration::from_secs(30);
 
-    pub fn new(weapon: &'static WeaponConfig, time: Duration) -> Self {
+    pub fn new(kind: BonusKind, time: Duration) -> Self {
         return Self {
-            weapon,
+            kind,
             expiration: time + Self::LIFETIME,
         };
     }
@@ -27,6 +27,17 @@ impl Bonus {
     }
 }
 
+#[derive(Clone)]
+pub enum BonusKind {
+    Weapon(Arc<WeaponConfig>),
+    Ammo {
+        projectile: Arc<ProjectileConfig>,
+        amount: u16,
+    },
+    Attachment(WeaponAttachment),
+    Consumable(Consumable),
+}
+
 #[derive(Component)]
 pub struct BonusImage;
 
@@ -1,8 +1,9 @@
 use crate::{
     data::BotConfig,
+    model::NavPath,
     util::{ext::RngExt, Timer},
 };
-use bevy::{ecs::component::Component, prelude::Entity};
+use bevy::{ecs::component::Component, math::Vec2, prelude::Entity};
 use rand::{Rng, SeedableRng};
 use rand_pcg::Pcg32;
 use std::{f32::consts::TAU, time::Duration};
@@ -11,7 +12,16 @@ use std::{f32::consts::TAU, time::Duration};
 pub struct Bot {
     pub config: BotConfig,
     pub enemy: Option<Entity>,
+    /// Enemies close to the current one, including it. Counted only for bots with grenades
+    pub enemy_group: usize,
     pub teammates: Vec<Entity>,
+    /// Where the enemy was seen the last time. Searched once the contact is lost
+    pub enemy_last_position: Option<Vec2>,
+    pub search_timer: Timer,
+    /// When the bot listened the last time, so noises aren't heard twice
+    pub listened: Duration,
+    pub path: NavPath,
+    pub path_timer: Timer,
     pub update_timer: Timer,
     pub voice_timer: Timer,
     pub idle_direction: f32,
@@ -29,7 +39,13 @@ impl Bot {
         return Self {
             config: config.clone_with(skill, &mut rng),
             enemy: None,
+            enemy_group: 0,
             teammates: Vec::new(),
+            enemy_last_position: None,
+            search_timer: Timer::default(),
+            listened: Duration::ZERO,
+            path: NavPath::default(),
+            path_timer: Timer::default(),
             update_timer: Timer::default(),
             voice_timer: Timer::default(),
             idle_direction: rng.gen_range(0.0..TAU),
@@ -48,9 +64,20 @@ impl Bot {
             .gen_range(-BotConfig::IDLE_ROTATION..BotConfig::IDLE_ROTATION);
     }
 
+    /// Makes the bot go check the position, giving up after a while
+    pub fn search(&mut self, position: Vec2, time: Duration) {
+        self.enemy_last_position = Some(position);
+        self.search_timer.set(time + BotConfig::SEARCH_DURATION);
+    }
+
+    pub fn stop_search(&mut self) {
+        self.enemy_last_position = None;
+        self.search_timer.disable();
+    }
+
     pub fn get_shooting_state(
         &mut self,
-        is_weapon_automatic: bool,
+        is_continuous_fire: bool,
         time: Duration,
     ) -> BotShootingState {
         if self.shooting_timer.is_ready_and_enabled(time) {
@@ -66,7 +93,7 @@ impl Bot {
                 }
             };
 
-            self.set_shooting_state(next_state, is_weapon_automatic, time);
+            self.set_shooting_state(next_state, is_continuous_fire, time);
         }
 
         return self.shooting_state;
@@ -75,13 +102,13 @@ impl Bot {
     pub fn set_shooting_state(
         &mut self,
         state: BotShootingState,
-        is_weapon_automatic: bool,
+        is_continuous_fire: bool,
         time: Duration,
     ) {
         let duration = match state {
             BotShootingState::Prepare => self.config.shoot_prepare_duration,
             BotShootingState::Shoot => {
-                if is_weapon_automatic {
+                if is_continuous_fire {
                     self.config.shoot_burst_duration
                 } else {
                     Duration::ZERO // longer time can result ActorAction::Attack changing multiple times
@@ -0,0 +1,63 @@
+use super::{StatusEffect, StatusEffectSet};
+use enumset::enum_set;
+use std::time::Duration;
+
+/// Item used up to treat or boost an actor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Consumable {
+    Bandage,
+    Medkit,
+    /// Numbs limb wounds, stun and slowdown
+    Painkillers,
+    /// Restores stamina
+    EnergyDrink,
+}
+
+impl Consumable {
+    pub const ALL: [Self; 4] = [
+        Self::Bandage,
+        Self::Medkit,
+        Self::Painkillers,
+        Self::EnergyDrink,
+    ];
+
+    pub fn name(self) -> &'static str {
+        return match self {
+            Self::Bandage => "Bandage",
+            Self::Medkit => "Medkit",
+            Self::Painkillers => "Painkillers",
+            Self::EnergyDrink => "Energy drink",
+        };
+    }
+
+    /// Time the actor is busy using the item and can't shoot
+    pub fn get_use_duration(self) -> Duration {
+        return match self {
+            Self::Bandage => Duration::from_millis(2500),
+            Self::Medkit => Duration::from_secs(5),
+            Self::Painkillers => Duration::from_millis(1500),
+            Self::EnergyDrink => Duration::from_secs(2),
+        };
+    }
+
+    /// Share of the max health restored
+    pub fn get_healing(self) -> f32 {
+        return match self {
+            Self::Bandage => 0.15,
+            Self::Medkit => 0.6,
+            Self::Painkillers | Self::EnergyDrink => 0.0,
+        };
+    }
+
+    pub fn get_cures(self) -> StatusEffectSet {
+        return match self {
+            Self::Bandage => enum_set!(StatusEffect::Bleeding),
+            // everything except what's still on fire
+            Self::Medkit => {
+                enum_set!(StatusEffect::Bleeding | StatusEffect::Stun | StatusEffect::Slowed)
+            }
+            Self::Painkillers => enum_set!(StatusEffect::Stun | StatusEffect::Slowed),
+            Self::EnergyDrink => StatusEffectSet::EMPTY,
+        };
+    }
+}
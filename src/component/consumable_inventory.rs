@@ -0,0 +1,130 @@
+use super::Consumable;
+use bevy::ecs::component::Component;
+use std::time::Duration;
+
+/// Consumables carried by an actor
+#[derive(Component, Default)]
+pub struct ConsumableInventory {
+    counts: [u8; Consumable::ALL.len()],
+    requested: Option<Consumable>,
+    using: Option<(Consumable, Duration)>,
+}
+
+impl ConsumableInventory {
+    pub const CAPACITY: u8 = 3;
+
+    /// Puts an item in the inventory. Returns false if there is no room for it
+    pub fn add(&mut self, item: Consumable) -> bool {
+        let count = &mut self.counts[Self::get_slot(item)];
+
+        if *count < Self::CAPACITY {
+            *count += 1;
+            return true;
+        } else {
+            return false;
+        }
+    }
+
+    pub fn request(&mut self, item: Consumable) {
+        self.requested = Some(item);
+    }
+
+    /// Takes the requested item out of the inventory if it can be used now
+    pub fn take_request(&mut self) -> Option<Consumable> {
+        let item = self.requested.take()?;
+        let count = &mut self.counts[Self::get_slot(item)];
+
+        if self.using.is_some() || *count == 0 {
+            return None;
+        }
+
+        *count -= 1;
+        return Some(item);
+    }
+
+    pub fn start_using(&mut self, item: Consumable, time: Duration) {
+        self.using = Some((item, time + item.get_use_duration()));
+    }
+
+    /// Returns the item once it has been used
+    pub fn take_used(&mut self, time: Duration) -> Option<Consumable> {
+        if let Some((item, finish_time)) = self.using {
+            if time >= finish_time {
+                self.using = None;
+                return Some(item);
+            }
+        }
+
+        return None;
+    }
+
+    pub fn get_count(&self, item: Consumable) -> u8 {
+        return self.counts[Self::get_slot(item)];
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.counts.iter().all(|c| *c == 0);
+    }
+
+    pub fn is_using(&self) -> bool {
+        return self.using.is_some();
+    }
+
+    fn get_slot(item: Consumable) -> usize {
+        return Consumable::ALL
+            .iter()
+            .position(|c| *c == item)
+            .unwrap_or_default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_use() {
+        let mut inventory = ConsumableInventory::default();
+        inventory.request(Consumable::Bandage);
+        assert_eq!(inventory.take_request(), None);
+
+        assert!(inventory.add(Consumable::Bandage));
+        assert!(!inventory.is_empty());
+        inventory.request(Consumable::Bandage);
+        let item = inventory.take_request().expect("No bandage");
+        assert_eq!(inventory.get_count(Consumable::Bandage), 0);
+        assert!(inventory.is_empty());
+
+        inventory.start_using(item, Duration::ZERO);
+        assert!(inventory.is_using());
+        assert_eq!(inventory.take_used(Duration::from_secs(1)), None);
+        assert_eq!(
+            inventory.take_used(Consumable::Bandage.get_use_duration()),
+            Some(Consumable::Bandage),
+        );
+        assert!(!inventory.is_using());
+    }
+
+    #[test]
+    fn test_capacity() {
+        let mut inventory = ConsumableInventory::default();
+
+        for _ in 0..ConsumableInventory::CAPACITY {
+            assert!(inventory.add(Consumable::Medkit));
+        }
+
+        assert!(!inventory.add(Consumable::Medkit));
+        assert!(inventory.add(Consumable::Painkillers));
+    }
+
+    #[test]
+    fn test_no_use_while_using() {
+        let mut inventory = ConsumableInventory::default();
+        inventory.add(Consumable::Bandage);
+        inventory.add(Consumable::Medkit);
+        inventory.start_using(Consumable::EnergyDrink, Duration::ZERO);
+        inventory.request(Consumable::Medkit);
+        assert_eq!(inventory.take_request(), None);
+        assert_eq!(inventory.get_count(Consumable::Medkit), 1);
+    }
+}
@@ -0,0 +1,160 @@
+use super::ProjectileConfig;
+use bevy::{
+    ecs::{component::Component, entity::Entity},
+    math::Vec2,
+};
+use std::{sync::Arc, time::Duration};
+
+/// A thrown explosive flying or rolling until its fuse burns out
+#[derive(Component)]
+pub struct Explosive {
+    pub config: Arc<ExplosiveConfig>,
+    pub thrower: Option<Entity>,
+    pub velocity: Vec2,
+    pub height: f32,
+    pub velocity_vertical: f32,
+    pub detonation_time: Duration,
+}
+
+pub struct ExplosiveConfig {
+    pub name: String,
+    pub fuse: Duration,
+    pub throw_velocity: f32,
+    pub radius: f32,
+    pub momentum: f32,
+    pub fragments: u8,
+    pub fragment: Arc<ProjectileConfig>,
+    pub fragment_velocity: f32,
+    /// Burning damage per second at the center, decreasing to zero at the radius
+    pub burning: f32,
+}
+
+impl ExplosiveConfig {
+    /// Velocity to land at the given distance with a 45 degree throw, limited by the throw velocity
+    pub fn get_throw_velocity(&self, distance: Option<f32>) -> f32 {
+        return distance.map_or(self.throw_velocity, |d| {
+            f32::min((d * Explosive::GRAVITY).sqrt(), self.throw_velocity)
+        });
+    }
+
+    /// Distance of the furthest throw, not counting the roll after landing
+    pub fn get_throw_distance(&self) -> f32 {
+        return self.throw_velocity * self.throw_velocity / Explosive::GRAVITY;
+    }
+
+    /// Blast momentum at the given distance from the center
+    pub fn get_momentum(&self, distance: f32) -> f32 {
+        return self.momentum * f32::max(1.0 - distance / self.radius, 0.0);
+    }
+}
+
+impl Explosive {
+    pub const GRAVITY: f32 = 9.8;
+    pub const RADIUS: f32 = 0.05;
+    const BOUNCE_FACTOR: f32 = 0.3;
+    const BOUNCE_VELOCITY_MIN: f32 = 1.0;
+    const GROUND_FRICTION: f32 = 0.6;
+    const ROLL_DRAG: f32 = 2.5;
+    const OBSTACLE_BOUNCE_FACTOR: f32 = 0.4;
+
+    /// Moves the explosive through the air or along the ground. Returns the horizontal shift
+    pub fn update(&mut self, delta: f32) -> Vec2 {
+        if self.height > 0.0 || self.velocity_vertical > 0.0 {
+            self.velocity_vertical -= Self::GRAVITY * delta;
+            self.height += self.velocity_vertical * delta;
+
+            if self.height <= 0.0 {
+                self.height = 0.0;
+
+                if -self.velocity_vertical > Self::BOUNCE_VELOCITY_MIN {
+                    self.velocity_vertical *= -Self::BOUNCE_FACTOR;
+                } else {
+                    self.velocity_vertical = 0.0;
+                }
+
+                self.velocity *= Self::GROUND_FRICTION;
+            }
+        } else {
+            self.velocity *= f32::max(1.0 - Self::ROLL_DRAG * delta, 0.0);
+        }
+
+        return self.velocity * delta;
+    }
+
+    /// Reflects the velocity from a static surface with the given normal
+    pub fn bounce(&mut self, normal: Vec2) {
+        let dot = self.velocity.dot(normal);
+
+        if dot < 0.0 {
+            self.velocity = (self.velocity - 2.0 * dot * normal) * Self::OBSTACLE_BOUNCE_FACTOR;
+        }
+    }
+
+    pub fn is_ready(&self, time: Duration) -> bool {
+        return time >= self.detonation_time;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> ExplosiveConfig {
+        return ExplosiveConfig {
+            name: "Test".to_string(),
+            fuse: Duration::from_secs(3),
+            throw_velocity: 10.0,
+            radius: 5.0,
+            momentum: 8.0,
+            fragments: 0,
+            fragment: Arc::new(ProjectileConfig {
+                caliber: "fragment".to_string(),
+                fragments: 1,
+                mass: 0.002,
+                size: 0.3,
+                penetration: 0.0,
+                armor_piercing: 0.0,
+            }),
+            fragment_velocity: 400.0,
+            burning: 0.0,
+        };
+    }
+
+    #[test]
+    fn test_momentum_falloff() {
+        let config = config();
+        assert_eq!(config.get_momentum(0.0), 8.0);
+        assert_eq!(config.get_momentum(2.5), 4.0);
+        assert_eq!(config.get_momentum(7.0), 0.0);
+    }
+
+    #[test]
+    fn test_throw_velocity() {
+        let config = config();
+        assert_eq!(config.get_throw_velocity(None), 10.0);
+        assert_eq!(config.get_throw_velocity(Some(100.0)), 10.0);
+        assert!((config.get_throw_velocity(Some(5.0)) - 7.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_lands_and_stops() {
+        let mut explosive = Explosive {
+            config: Arc::new(config()),
+            thrower: None,
+            velocity: Vec2::new(5.0, 0.0),
+            height: 1.5,
+            velocity_vertical: 5.0,
+            detonation_time: Duration::ZERO,
+        };
+
+        let mut shift = Vec2::ZERO;
+
+        for _ in 0..600 {
+            shift += explosive.update(0.01);
+        }
+
+        assert_eq!(explosive.height, 0.0);
+        assert!(explosive.velocity.length() < 0.01);
+        assert!(shift.x > 3.0);
+    }
+}
@@ -0,0 +1,41 @@
+use super::ExplosiveConfig;
+use bevy::ecs::component::Component;
+use std::{sync::Arc, time::Duration};
+
+/// Explosives carried by an actor to be thrown
+#[derive(Component)]
+pub struct Grenades {
+    pub config: Arc<ExplosiveConfig>,
+    pub count: u8,
+    /// Distance the next grenade is aimed at. The furthest throw if not set
+    pub target_distance: Option<f32>,
+    next_time: Duration,
+}
+
+impl Grenades {
+    const INTERVAL: Duration = Duration::from_secs(2);
+
+    pub fn new(config: Arc<ExplosiveConfig>, count: u8) -> Self {
+        return Self {
+            config,
+            count,
+            target_distance: None,
+            next_time: Duration::ZERO,
+        };
+    }
+
+    /// Takes a grenade to throw if there is any and the previous throw is over
+    pub fn take(&mut self, time: Duration) -> Option<Arc<ExplosiveConfig>> {
+        if !self.is_ready(time) {
+            return None;
+        }
+
+        self.count -= 1;
+        self.next_time = time + Self::INTERVAL;
+        return Some(Arc::clone(&self.config));
+    }
+
+    pub fn is_ready(&self, time: Duration) -> bool {
+        return self.count > 0 && time >= self.next_time;
+    }
+}
@@ -1,3 +1,4 @@
+use crate::model::Attack;
 use bevy::ecs::component::Component;
 
 #[derive(Component)]
@@ -5,6 +6,7 @@ pub struct Health {
     value_max: f32,
     value: f32,
     value_previous: f32,
+    last_attack: Option<Attack>,
 }
 
 impl Health {
@@ -15,6 +17,7 @@ impl Health {
             value_max,
             value: value_max,
             value_previous: value_max,
+            last_attack: None,
         };
     }
 
@@ -22,17 +25,40 @@ impl Health {
         self.value = (self.value - damage).clamp(0.0, self.value_max);
     }
 
+    pub fn kill(&mut self) {
+        self.value = 0.0;
+    }
+
     pub fn heal(&mut self) {
         if self.is_alive() {
             self.value = self.value_max;
         }
     }
 
+    /// Restores a share of the max health
+    pub fn restore(&mut self, value_normalized: f32) {
+        if self.is_alive() {
+            self.value = f32::min(
+                self.value + value_normalized * self.value_max,
+                self.value_max,
+            );
+        }
+    }
+
+    pub fn set_last_attack(&mut self, attack: Attack) {
+        self.last_attack = Some(attack);
+    }
+
     /// NOTE: only heath system can call this method to commit health changes
     pub fn commit(&mut self) {
         self.value_previous = self.value;
     }
 
+    /// The last attack on the actor, to credit its death
+    pub fn get_last_attack(&self) -> Option<&Attack> {
+        return self.last_attack.as_ref();
+    }
+
     pub fn get_normalized(&self) -> f32 {
         return self.value / self.value_max;
     }
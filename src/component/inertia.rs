@@ -40,6 +40,17 @@ impl Inertia {
         }
     }
 
+    /// Momentum which stops moving into a static surface with the given normal
+    pub fn bounce_static(&self, normal: Vec2) -> Vec2 {
+        let dot = Vec2::dot(self.velocity, normal);
+
+        if dot < 0.0 && dot.is_finite() {
+            return -dot * self.mass * (1.0 + Self::RIGIDITY) * normal;
+        } else {
+            return Vec2::new(0.0, 0.0);
+        }
+    }
+
     pub fn push(
         &mut self,
         mut force: Vec2,
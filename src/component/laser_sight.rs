@@ -0,0 +1,28 @@
+use crate::data::PIXELS_PER_METER;
+use bevy::{
+    ecs::component::Component,
+    math::{Quat, Vec3},
+    transform::components::Transform,
+};
+use std::f32::consts::PI;
+
+/// Laser sight beam attached to an actor holding a weapon with the sight mounted
+#[derive(Component)]
+pub struct LaserSight;
+
+impl LaserSight {
+    /// Maximum beam length, meters
+    pub const LENGTH: f32 = 26.0;
+    /// Distance from the actor center to the beam start, meters
+    pub const OFFSET: f32 = 0.5;
+    const THICKNESS: f32 = 0.5 * PIXELS_PER_METER;
+
+    /// Beam transform relative to the actor for the given length in meters
+    pub fn get_transform(length: f32) -> Transform {
+        return Transform {
+            translation: Vec3::new((length / 2.0 + Self::OFFSET) * PIXELS_PER_METER, 0.0, -1.0),
+            scale: Vec3::new(length * PIXELS_PER_METER, Self::THICKNESS, 1.0),
+            rotation: Quat::from_rotation_z(PI),
+        };
+    }
+}
@@ -1,21 +1,35 @@
 mod actor;
+mod ammo_reserve;
+mod armor;
 mod audio_expiration;
+mod beam;
 mod bonus;
 mod bot;
 mod breath;
 mod collision;
+mod consumable;
+mod consumable_inventory;
+mod explosive;
 mod footsteps;
+mod grenades;
 mod health;
 mod heartbeat;
 mod inertia;
+mod laser_sight;
 mod notification;
+mod obstacle;
 mod player;
 mod projectile;
+mod status_effects;
 mod terrain;
 mod weapon;
+mod weapon_attachment;
+mod weapon_inventory;
 
 pub use self::{
-    actor::*, audio_expiration::*, bonus::*, bot::*, breath::*, collision::*, footsteps::*,
-    health::*, heartbeat::*, inertia::*, notification::*, player::*, projectile::*, terrain::*,
-    weapon::*,
+    actor::*, ammo_reserve::*, armor::*, audio_expiration::*, beam::*, bonus::*, bot::*, breath::*,
+    collision::*, consumable::*, consumable_inventory::*, explosive::*, footsteps::*, grenades::*,
+    health::*, heartbeat::*, inertia::*, laser_sight::*, notification::*, obstacle::*, player::*,
+    projectile::*, status_effects::*, terrain::*, weapon::*, weapon_attachment::*,
+    weapon_inventory::*,
 };
@@ -0,0 +1,233 @@
+use crate::{model::geometry::LineSegment, util::ext::Vec2Ext};
+use bevy::{ecs::component::Component, math::Vec2};
+
+const EXTRA_RESOLVE_DISTANCE: f32 = 0.0001;
+
+/// Static collider centered at the entity translation
+#[derive(Component, Clone, Copy)]
+pub enum Obstacle {
+    Circle {
+        radius: f32,
+    },
+    /// Axis-aligned box
+    Box {
+        half_size: Vec2,
+    },
+}
+
+pub struct ObstacleContact {
+    pub position: Vec2,
+    /// Normal of the surface at the contact position
+    pub normal: Vec2,
+    /// Position along the segment, 0.0 at its start and 1.0 at its end
+    pub fraction: f32,
+}
+
+impl Obstacle {
+    /// Finds where the line segment enters the obstacle. Segments starting inside are ignored
+    pub fn intersect(&self, center: Vec2, segment: &LineSegment) -> Option<ObstacleContact> {
+        let origin = segment.0 - center;
+        let delta = segment.1 - segment.0;
+
+        let (fraction, normal) = match self {
+            Self::Circle { radius } => intersect_circle(origin, delta, *radius)?,
+            Self::Box { half_size } => intersect_box(origin, delta, *half_size)?,
+        };
+
+        return Some(ObstacleContact {
+            position: segment.0 + delta * fraction,
+            normal,
+            fraction,
+        });
+    }
+
+    /// Half size of the bounding box
+    pub fn get_half_size(&self) -> Vec2 {
+        return match self {
+            Self::Circle { radius } => Vec2::splat(*radius),
+            Self::Box { half_size } => *half_size,
+        };
+    }
+
+    /// Finds how to shift a circle body out of the obstacle. Returns the shift and the surface normal
+    pub fn resolve(&self, center: Vec2, position: Vec2, radius: f32) -> Option<(Vec2, Vec2)> {
+        let local = position - center;
+
+        let (normal, depth) = match self {
+            Self::Circle {
+                radius: obstacle_radius,
+            } => {
+                let distance_min = obstacle_radius + radius;
+
+                if local.is_long(distance_min) {
+                    return None;
+                }
+
+                let normal = local.try_normalize().unwrap_or(Vec2::FRONT);
+                (normal, distance_min - local.length())
+            }
+            Self::Box { half_size } => {
+                let closest = local.clamp(-*half_size, *half_size);
+
+                if closest == local {
+                    // the center is inside, push out through the nearest side
+                    let depth = *half_size - local.abs();
+
+                    if depth.x < depth.y {
+                        (Vec2::new(local.x.signum(), 0.0), depth.x + radius)
+                    } else {
+                        (Vec2::new(0.0, local.y.signum()), depth.y + radius)
+                    }
+                } else {
+                    let delta = local - closest;
+
+                    if delta.is_long(radius) {
+                        return None;
+                    }
+
+                    let distance = delta.length();
+                    (delta / distance, radius - distance)
+                }
+            }
+        };
+
+        return Some((normal * (depth + EXTRA_RESOLVE_DISTANCE), normal));
+    }
+}
+
+fn intersect_circle(origin: Vec2, delta: Vec2, radius: f32) -> Option<(f32, Vec2)> {
+    let a = delta.length_squared();
+    let b = origin.dot(delta);
+    let c = origin.length_squared() - radius * radius;
+
+    if a == 0.0 || c < 0.0 {
+        return None;
+    }
+
+    let discriminant = b * b - a * c;
+
+    if discriminant < 0.0 {
+        return None;
+    }
+
+    let t = (-b - discriminant.sqrt()) / a;
+
+    if (0.0..=1.0).contains(&t) {
+        return Some((t, (origin + delta * t).normalize_or_zero()));
+    } else {
+        return None;
+    }
+}
+
+fn intersect_box(origin: Vec2, delta: Vec2, half_size: Vec2) -> Option<(f32, Vec2)> {
+    let mut t_enter = 0.0;
+    let mut t_exit = 1.0;
+    let mut normal = Vec2::ZERO;
+
+    for axis in 0..2 {
+        let o = origin[axis];
+        let d = delta[axis];
+        let h = half_size[axis];
+
+        if d == 0.0 {
+            if o.abs() > h {
+                return None;
+            }
+
+            continue;
+        }
+
+        let mut t0 = (-h - o) / d;
+        let mut t1 = (h - o) / d;
+        let mut side = -1.0;
+
+        if t0 > t1 {
+            std::mem::swap(&mut t0, &mut t1);
+            side = 1.0;
+        }
+
+        if t0 > t_enter {
+            t_enter = t0;
+            normal = Vec2::ZERO;
+            normal[axis] = side;
+        }
+
+        t_exit = f32::min(t_exit, t1);
+
+        if t_enter > t_exit {
+            return None;
+        }
+    }
+
+    if normal == Vec2::ZERO {
+        return None;
+    } else {
+        return Some((t_enter, normal));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intersect_circle() {
+        let obstacle = Obstacle::Circle { radius: 1.0 };
+        let segment = (Vec2::new(-3.0, 0.0), Vec2::new(1.0, 0.0));
+        let contact = obstacle
+            .intersect(Vec2::ZERO, &segment)
+            .expect("No contact");
+        assert_eq!(contact.position, Vec2::new(-1.0, 0.0));
+        assert_eq!(contact.normal, Vec2::new(-1.0, 0.0));
+        assert_eq!(contact.fraction, 0.5);
+    }
+
+    #[test]
+    fn test_intersect_circle_miss() {
+        let obstacle = Obstacle::Circle { radius: 1.0 };
+        let segment = (Vec2::new(-3.0, 2.0), Vec2::new(3.0, 2.0));
+        assert!(obstacle.intersect(Vec2::ZERO, &segment).is_none());
+    }
+
+    #[test]
+    fn test_intersect_box() {
+        let obstacle = Obstacle::Box {
+            half_size: Vec2::new(2.0, 1.0),
+        };
+
+        let segment = (Vec2::new(1.0, 5.0), Vec2::new(1.0, 3.0));
+        let contact = obstacle
+            .intersect(Vec2::new(0.0, 2.0), &segment)
+            .expect("No contact");
+
+        assert_eq!(contact.position, Vec2::new(1.0, 3.0));
+        assert_eq!(contact.normal, Vec2::new(0.0, 1.0));
+    }
+
+    #[test]
+    fn test_resolve_box() {
+        let obstacle = Obstacle::Box {
+            half_size: Vec2::new(2.0, 1.0),
+        };
+
+        let (shift, normal) = obstacle
+            .resolve(Vec2::ZERO, Vec2::new(2.2, 0.5), 0.5)
+            .expect("No collision");
+
+        assert_eq!(normal, Vec2::new(1.0, 0.0));
+        assert!((shift.x - 0.3).abs() < 0.001);
+        assert!(obstacle
+            .resolve(Vec2::ZERO, Vec2::new(2.6, 0.5), 0.5)
+            .is_none());
+    }
+
+    #[test]
+    fn test_intersect_box_from_inside() {
+        let obstacle = Obstacle::Box {
+            half_size: Vec2::new(2.0, 1.0),
+        };
+
+        let segment = (Vec2::ZERO, Vec2::new(5.0, 0.0));
+        assert!(obstacle.intersect(Vec2::ZERO, &segment).is_none());
+    }
+}
@@ -9,11 +9,15 @@ pub struct Player {
     shake: Shake,
     shake_abs: Shake,
     extra_rotation: f32,
+    is_aiming: bool,
+    aim: f32,
 }
 
 impl Player {
     pub const EXTRA_ROTATION_MULTIPLAYER: f32 = 0.1;
     pub const EXTRA_ROTATION_MAX: f32 = 0.11;
+    const AIM_SHAKE: f32 = 0.5;
+    const AIM_SPEED: Duration = Duration::from_millis(150);
 
     pub fn new(is_controllable: bool) -> Self {
         return Self {
@@ -22,6 +26,8 @@ impl Player {
             shake: Shake::default(),
             shake_abs: Shake::default(),
             extra_rotation: 0.0,
+            is_aiming: false,
+            aim: 0.0,
         };
     }
 
@@ -29,6 +35,13 @@ impl Player {
         self.zoom.update(delta);
         self.shake.update(delta);
         self.shake_abs.update(delta);
+
+        let aim_target = if self.is_aiming {
+            1.0
+        } else {
+            0.0
+        };
+        self.aim += (aim_target - self.aim) * Self::AIM_SPEED.delta(delta);
     }
 
     pub fn add_extra_rotation(&mut self, value: f32) -> f32 {
@@ -43,7 +56,24 @@ impl Player {
         self.zoom.add(zoom);
     }
 
-    pub fn shake(&mut self, shake: f32) {
+    /// Lets zoom further while aiming through a scope
+    pub fn set_scope(&mut self, has_scope: bool) {
+        self.zoom.max = if has_scope {
+            Zoom::MAX_SCOPE
+        } else {
+            Zoom::MAX
+        };
+    }
+
+    pub fn set_aiming(&mut self, is_aiming: bool) {
+        self.is_aiming = is_aiming;
+    }
+
+    pub fn shake(&mut self, mut shake: f32) {
+        if self.is_aiming {
+            shake *= Self::AIM_SHAKE;
+        }
+
         self.shake.add(shake);
         self.shake_abs.add(shake.abs());
     }
@@ -60,6 +90,11 @@ impl Player {
         return self.shake_abs.get();
     }
 
+    /// How far the weapon is raised to aim, from 0.0 at the hip to 1.0 down the sights
+    pub fn get_aim(&self) -> f32 {
+        return self.aim;
+    }
+
     pub fn get_extra_rotation(&self) -> f32 {
         return self.extra_rotation;
     }
@@ -69,6 +104,7 @@ struct Zoom {
     value: f32,
     value_target: f32,
     speed: Duration,
+    max: f32,
 }
 
 impl Default for Zoom {
@@ -77,6 +113,7 @@ impl Default for Zoom {
             value: Self::MAX,
             value_target: Self::DEFAULT,
             speed: Self::SPEED_INITIAL,
+            max: Self::MAX,
         };
     }
 }
@@ -86,14 +123,16 @@ impl Zoom {
 
     const MIN: f32 = 1.0;
     const MAX: f32 = 5.0;
+    const MAX_SCOPE: f32 = 8.0;
     const DEFAULT: f32 = 2.0;
 
     const SPEED_INITIAL: Duration = Duration::from_millis(5000);
     const SPEED_MANUAL: Duration = Duration::from_millis(125);
 
     pub fn update(&mut self, delta: f32) {
+        self.value_target = self.clamp(self.value_target);
         self.value += (self.value_target - self.value) * self.speed.delta(delta);
-        self.value = Self::clamp(self.value);
+        self.value = self.clamp(self.value);
     }
 
     pub fn add(&mut self, zoom: f32) {
@@ -102,7 +141,7 @@ impl Zoom {
         }
 
         self.value_target += zoom * self.value_target * Self::SENSITIVITY;
-        self.value_target = Self::clamp(self.value_target);
+        self.value_target = self.clamp(self.value_target);
         self.speed = Self::SPEED_MANUAL;
     }
 
@@ -110,8 +149,8 @@ impl Zoom {
         return self.value;
     }
 
-    fn clamp(value: f32) -> f32 {
-        return value.clamp(Self::MIN, Self::MAX);
+    fn clamp(&self, value: f32) -> f32 {
+        return value.clamp(Self::MIN, self.max);
     }
 }
 
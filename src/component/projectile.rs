@@ -1,56 +1,41 @@
+use super::WeaponConfig;
 use bevy::{
     ecs::{component::Component, entity::Entity},
     math::Vec2,
 };
-use std::time::Duration;
+use serde::Deserialize;
+use std::{sync::Arc, time::Duration};
 
 #[derive(Component)]
 pub struct Projectile {
-    pub config: &'static ProjectileConfig,
+    pub config: Arc<ProjectileConfig>,
     pub initial_time: Duration,
     pub initial_position: Vec2,
     pub initial_velocity: Vec2,
     pub shooter: Option<Entity>,
+    /// Weapon which fired the projectile. None for explosive fragments
+    pub weapon: Option<Arc<WeaponConfig>>,
+    /// The last obstacle the projectile has passed through
+    pub penetrated: Option<Entity>,
     pub stopped: bool,
 }
 
-#[derive(Clone)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct ProjectileConfig {
+    pub caliber: String,
     pub fragments: u8,
     pub mass: f32,
     pub size: f32,
+    #[serde(default)]
+    pub penetration: f32,
+    /// Extra momentum share counted against armor
+    #[serde(default)]
+    pub armor_piercing: f32,
 }
 
 impl ProjectileConfig {
-    pub const _9X18: Self = Self {
-        fragments: 1,
-        mass: 0.0061,
-        size: 0.7,
-    };
-
-    pub const _7_62X25: Self = Self {
-        fragments: 1,
-        mass: 0.0055,
-        size: 0.7,
-    };
-
-    pub const _12X76: Self = Self {
-        fragments: 12,
-        mass: 0.048,
-        size: 0.1,
-    };
-
-    pub const _5_45X39: Self = Self {
-        fragments: 1,
-        mass: 0.0034,
-        size: 1.0,
-    };
-
-    pub const _7_62X54: Self = Self {
-        fragments: 1,
-        mass: 0.0096,
-        size: 1.2,
-    };
+    /// Momentum a fragment loses while passing through a body
+    const BODY_RESISTANCE: f32 = 3.0;
 
     pub fn acceleration(&self) -> f32 {
         return -1.0 / self.fragment_mass() * 0.006 - 4.2;
@@ -59,18 +44,30 @@ impl ProjectileConfig {
     pub fn fragment_mass(&self) -> f32 {
         return self.mass / f32::from(self.fragments);
     }
+
+    /// Share of velocity a fragment keeps after passing through a body. Zero if it gets stuck
+    pub fn penetrate_body(&self, velocity: f32) -> f32 {
+        let momentum = velocity * self.fragment_mass() * self.penetration;
+
+        if momentum > Self::BODY_RESISTANCE {
+            return 1.0 - Self::BODY_RESISTANCE / momentum;
+        } else {
+            return 0.0;
+        }
+    }
 }
 
 impl Projectile {
     pub const VELOCITY_MIN: f32 = 5.0;
     pub const VELOCITY_VISUAL_FACTOR: f32 = 1.0 / 5.0;
 
-    pub const fn new(
-        config: &'static ProjectileConfig,
+    pub fn new(
+        config: Arc<ProjectileConfig>,
         time: Duration,
         position: Vec2,
         velocity: Vec2,
         shooter: Option<Entity>,
+        weapon: Option<Arc<WeaponConfig>>,
     ) -> Self {
         return Self {
             config,
@@ -78,10 +75,25 @@ impl Projectile {
             initial_position: position,
             initial_velocity: velocity,
             shooter,
+            weapon,
+            penetrated: None,
             stopped: false,
         };
     }
 
+    /// Continues the flight from the obstacle the projectile has passed through
+    pub fn penetrate(&mut self, obstacle: Entity, time: Duration, position: Vec2, velocity: Vec2) {
+        self.redirect(time, position, velocity);
+        self.penetrated = Some(obstacle);
+    }
+
+    /// Continues the flight from the given position with a new velocity
+    pub fn redirect(&mut self, time: Duration, position: Vec2, velocity: Vec2) {
+        self.initial_time = time;
+        self.initial_position = position;
+        self.initial_velocity = velocity;
+    }
+
     pub fn calc_data(&self, time: Duration) -> (Vec2, Vec2) {
         let t = time.saturating_sub(self.initial_time).as_secs_f32();
         let a = self.config.acceleration();
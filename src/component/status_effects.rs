@@ -0,0 +1,162 @@
+use bevy::ecs::component::Component;
+use enumset::{EnumSet, EnumSetType};
+use std::time::Duration;
+
+/// Timed condition lingering on an actor after a hit
+#[derive(Debug, EnumSetType)]
+pub enum StatusEffect {
+    /// Damage over time. Intensity is health per second, stacks up
+    Bleeding,
+    /// Can't move nor act
+    Stun,
+    /// Movement slowdown share
+    Slowed,
+    /// Damage over time. Intensity is health per second, the strongest fire wins
+    Burning,
+}
+
+pub type StatusEffectSet = EnumSet<StatusEffect>;
+
+impl StatusEffect {
+    const BLEEDING_MAX: f32 = 0.5;
+
+    /// Whether a new effect of the same kind adds its intensity to the current one
+    /// instead of replacing it if stronger
+    fn is_stackable(self) -> bool {
+        return match self {
+            Self::Bleeding => true,
+            Self::Stun | Self::Slowed | Self::Burning => false,
+        };
+    }
+
+    fn get_intensity_max(self) -> f32 {
+        return match self {
+            Self::Bleeding => Self::BLEEDING_MAX,
+            Self::Stun | Self::Slowed => 1.0,
+            Self::Burning => f32::INFINITY,
+        };
+    }
+}
+
+#[derive(Component, Default)]
+pub struct StatusEffects {
+    effects: Vec<ActiveEffect>,
+}
+
+struct ActiveEffect {
+    kind: StatusEffect,
+    intensity: f32,
+    expiration: Duration,
+}
+
+impl StatusEffects {
+    /// Applies an effect. An effect of the same kind is merged with the new one,
+    /// lasting till the later expiration
+    pub fn add(&mut self, kind: StatusEffect, intensity: f32, duration: Duration, time: Duration) {
+        let expiration = time + duration;
+
+        if let Some(effect) = self.effects.iter_mut().find(|e| e.kind == kind) {
+            if kind.is_stackable() {
+                effect.intensity += intensity;
+            } else {
+                effect.intensity = f32::max(effect.intensity, intensity);
+            }
+
+            effect.intensity = f32::min(effect.intensity, kind.get_intensity_max());
+            effect.expiration = Duration::max(effect.expiration, expiration);
+        } else {
+            self.effects.push(ActiveEffect {
+                kind,
+                intensity: f32::min(intensity, kind.get_intensity_max()),
+                expiration,
+            });
+        }
+    }
+
+    pub fn cure(&mut self, kinds: StatusEffectSet) {
+        self.effects.retain(|e| !kinds.contains(e.kind));
+    }
+
+    /// Drops expired effects
+    pub fn update(&mut self, time: Duration) {
+        self.effects.retain(|e| e.expiration > time);
+    }
+
+    /// Intensity of an effect, zero if it's not active
+    pub fn get(&self, kind: StatusEffect) -> f32 {
+        return self
+            .effects
+            .iter()
+            .find(|e| e.kind == kind)
+            .map_or(0.0, |e| e.intensity);
+    }
+
+    /// Health lost per second
+    pub fn get_damage_rate(&self) -> f32 {
+        return self.get(StatusEffect::Bleeding) + self.get(StatusEffect::Burning);
+    }
+
+    pub fn get_movement_factor(&self) -> f32 {
+        if self.is_stunned() {
+            return 0.0;
+        } else {
+            return 1.0 - self.get(StatusEffect::Slowed);
+        }
+    }
+
+    pub fn is_stunned(&self) -> bool {
+        return self.get(StatusEffect::Stun) > 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use enumset::enum_set;
+
+    const SECOND: Duration = Duration::from_secs(1);
+
+    #[test]
+    fn test_bleeding_stacks() {
+        let mut effects = StatusEffects::default();
+        effects.add(StatusEffect::Bleeding, 0.1, SECOND, Duration::ZERO);
+        effects.add(StatusEffect::Bleeding, 0.1, SECOND * 2, Duration::ZERO);
+        assert_eq!(effects.get(StatusEffect::Bleeding), 0.2);
+
+        effects.add(StatusEffect::Bleeding, 1.0, SECOND, Duration::ZERO);
+        assert_eq!(
+            effects.get(StatusEffect::Bleeding),
+            StatusEffect::BLEEDING_MAX
+        );
+
+        effects.update(SECOND * 3 / 2);
+        assert_eq!(
+            effects.get(StatusEffect::Bleeding),
+            StatusEffect::BLEEDING_MAX
+        );
+
+        effects.update(SECOND * 2);
+        assert_eq!(effects.get(StatusEffect::Bleeding), 0.0);
+    }
+
+    #[test]
+    fn test_strongest_wins() {
+        let mut effects = StatusEffects::default();
+        effects.add(StatusEffect::Slowed, 0.5, SECOND, Duration::ZERO);
+        effects.add(StatusEffect::Slowed, 0.3, SECOND, Duration::ZERO);
+        assert_eq!(effects.get_movement_factor(), 0.5);
+
+        effects.add(StatusEffect::Stun, 1.0, SECOND, Duration::ZERO);
+        assert_eq!(effects.get_movement_factor(), 0.0);
+    }
+
+    #[test]
+    fn test_cure() {
+        let mut effects = StatusEffects::default();
+        effects.add(StatusEffect::Bleeding, 0.1, SECOND, Duration::ZERO);
+        effects.add(StatusEffect::Burning, 0.1, SECOND, Duration::ZERO);
+        effects.cure(enum_set!(StatusEffect::Bleeding | StatusEffect::Stun));
+        assert_eq!(effects.get(StatusEffect::Bleeding), 0.0);
+        assert_eq!(effects.get_damage_rate(), 0.1);
+    }
+}
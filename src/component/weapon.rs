@@ -1,5 +1,6 @@
 use crate::{
-    component::ProjectileConfig,
+    component::{ProjectileConfig, WeaponAttachment, WeaponAttachments},
+    data::deserialize_seconds,
     util::{
         ext::{DurationExt, RngExt},
         math::interpolate,
@@ -7,314 +8,182 @@ use crate::{
 };
 use bevy::ecs::component::Component;
 use rand_pcg::Pcg32;
-use std::time::Duration;
+use serde::Deserialize;
+use std::{sync::Arc, time::Duration};
 
 const ARMING_DURATION: Duration = Duration::from_millis(300);
+const OVERHEAT_RECOVERY: f32 = 0.5;
+const AIM_DEVIATION: f32 = 0.5;
+const BLOOM_PER_RECOIL: f32 = 0.0015;
+const BLOOM_MAX: f32 = 0.2;
+const BLOOM_RECOVERY: Duration = Duration::from_millis(400);
 
 #[derive(Component)]
 pub struct Weapon {
-    pub config: &'static WeaponConfig,
+    pub config: Arc<WeaponConfig>,
+    attachments: WeaponAttachments,
     is_armed: bool,
     is_cocked: bool,
     is_trigger_pressed: bool,
     reloading: Option<Duration>,
     ammo: u8,
+    fire_mode: usize,
+    burst_left: u8,
     next_time: Duration,
+    heat: f32,
+    heat_time: Duration,
+    is_overheated: bool,
+    bloom: f32,
 }
 
+#[derive(Debug)]
 pub struct WeaponConfig {
-    pub name: &'static str,
+    pub id: String,
+    pub name: String,
     pub mass: f32,
     pub level: u8,
     pub muzzle_velocity: f32,
     pub deviation: f32,
     pub fire_rate: f32,
-    pub is_automatic: bool,
-    pub projectile: &'static ProjectileConfig,
+    /// Supported fire modes, the first one is the default. Never empty
+    pub fire_modes: Vec<FireMode>,
+    pub burst_size: u8,
+    /// Ballistic ammunition. None for hitscan weapons
+    pub projectile: Option<Arc<ProjectileConfig>>,
+    pub hitscan: Option<HitscanConfig>,
+    pub melee: Option<MeleeConfig>,
     pub ammo_capacity: u8,
     pub reloading_time: Duration,
     pub partial_reloading: bool,
+    /// Extra zoom while aiming down sights, relative to the current zoom
+    pub aim_zoom: f32,
+    /// Attachments which can be mounted
+    pub attachments: WeaponAttachments,
     pub grip: WeaponGrip,
     pub image_offset: f32,
 }
 
+/// Weapon which hits instantly along a ray and heats up instead of using magazines
+#[derive(Debug, Deserialize)]
+pub struct HitscanConfig {
+    /// Momentum transferred to the target
+    pub momentum: f32,
+    pub range: f32,
+    /// Heat added by a shot. The weapon overheats at 1.0
+    pub heat_per_shot: f32,
+    /// Heat dissipated per second
+    pub cooling: f32,
+}
+
+/// Weapon which strikes actors in reach instead of shooting
+#[derive(Debug, Deserialize)]
+pub struct MeleeConfig {
+    /// Momentum transferred to the target
+    pub damage: f32,
+    /// Distance from the actor body, meters
+    pub reach: f32,
+    /// Angle covered by a swing, radians
+    pub arc: f32,
+    #[serde(deserialize_with = "deserialize_seconds")]
+    pub swing_time: Duration,
+}
+
 impl WeaponConfig {
     const VELOCITY_DEVIATION: f32 = 0.06;
-    const SEMI_AUTO_FIRE_RATE: f32 = 400.0;
 
-    const RELOADING_TIME_PISTOL: Duration = Duration::from_millis(3000);
-    const RELOADING_TIME_SHOTGUN_LIGHT: Duration = Duration::from_millis(1200);
-    const RELOADING_TIME_SHOTGUN: Duration = Duration::from_millis(1800);
-    const RELOADING_TIME_SMG: Duration = Duration::from_millis(3800);
-    const RELOADING_TIME_CARBINE: Duration = Duration::from_millis(4200);
-    const RELOADING_TIME_RIFLE: Duration = Duration::from_millis(5000);
-    const RELOADING_TIME_RIFLE_HEAVY: Duration = Duration::from_millis(5800);
-    const RELOADING_TIME_MACHINE_GUN: Duration = Duration::from_millis(10000);
+    const BASH_DAMAGE_BASE: f32 = 0.3;
+    const BASH_DAMAGE_PER_MASS: f32 = 0.2;
+    const BASH_INTERVAL_BASE: Duration = Duration::from_millis(400);
+    const BASH_INTERVAL_PER_MASS: Duration = Duration::from_millis(100);
 
     const RECOIL_MASS_POW: f32 = 0.25;
     const RECOIL_POW: f32 = 0.5;
     const RECOIL_MUL: f32 = 13.0;
 
-    pub const ALL: [Self; 12] = [
-        Self::PM,
-        Self::TT,
-        Self::MP_43_SAWED_OFF,
-        Self::PP_91_KEDR,
-        Self::MP_27,
-        Self::PP_19_BIZON,
-        Self::AKS_74U,
-        Self::AK_74M,
-        Self::RPK_74,
-        Self::SAIGA_12K,
-        Self::PKM,
-        Self::PKP_PECHENEG,
-    ];
-
-    pub const PM: Self = Self {
-        name: "PM",
-        mass: 0.73,
-        level: 1,
-        muzzle_velocity: 315.0,
-        deviation: 0.03,
-        fire_rate: Self::SEMI_AUTO_FIRE_RATE,
-        is_automatic: false,
-        projectile: &ProjectileConfig::_9X18,
-        ammo_capacity: 8,
-        reloading_time: Self::RELOADING_TIME_PISTOL,
-        partial_reloading: false,
-        grip: WeaponGrip::OneHand,
-        image_offset: 2.0,
-    };
-
-    pub const TT: Self = Self {
-        name: "TT",
-        mass: 0.85,
-        level: 1,
-        muzzle_velocity: 430.0,
-        deviation: 0.025,
-        fire_rate: Self::SEMI_AUTO_FIRE_RATE,
-        is_automatic: false,
-        projectile: &ProjectileConfig::_7_62X25,
-        ammo_capacity: 8,
-        reloading_time: Self::RELOADING_TIME_PISTOL,
-        partial_reloading: false,
-        grip: WeaponGrip::OneHand,
-        image_offset: 2.0,
-    };
-
-    pub const MP_43_SAWED_OFF: Self = Self {
-        name: "MP-43 sawed-off",
-        mass: 2.2,
-        level: 2,
-        muzzle_velocity: 260.0,
-        deviation: 0.06,
-        fire_rate: Self::SEMI_AUTO_FIRE_RATE,
-        is_automatic: false,
-        projectile: &ProjectileConfig::_12X76,
-        ammo_capacity: 2,
-        reloading_time: Self::RELOADING_TIME_SHOTGUN_LIGHT,
-        partial_reloading: true,
-        grip: WeaponGrip::TwoHands,
-        image_offset: 3.5,
-    };
-
-    pub const PP_91_KEDR: Self = Self {
-        name: "PP-91 Kedr",
-        mass: 1.6,
-        level: 2,
-        muzzle_velocity: 310.0,
-        deviation: 0.02,
-        fire_rate: 900.0,
-        is_automatic: true,
-        projectile: &ProjectileConfig::_9X18,
-        ammo_capacity: 20,
-        reloading_time: Self::RELOADING_TIME_SMG,
-        partial_reloading: false,
-        grip: WeaponGrip::TwoHands,
-        image_offset: 3.5,
-    };
-
-    pub const MP_27: Self = Self {
-        name: "MP-27",
-        mass: 3.2,
-        level: 3,
-        muzzle_velocity: 410.0,
-        deviation: 0.03,
-        fire_rate: Self::SEMI_AUTO_FIRE_RATE,
-        is_automatic: false,
-        projectile: &ProjectileConfig::_12X76,
-        ammo_capacity: 2,
-        reloading_time: Self::RELOADING_TIME_SHOTGUN,
-        partial_reloading: true,
-        grip: WeaponGrip::TwoHandsWithButt,
-        image_offset: 10.0,
-    };
-
-    pub const PP_19_BIZON: Self = Self {
-        name: "PP-19 Bizon",
-        mass: 2.7,
-        level: 3,
-        muzzle_velocity: 330.0,
-        deviation: 0.015,
-        fire_rate: 680.0,
-        is_automatic: true,
-        projectile: &ProjectileConfig::_9X18,
-        ammo_capacity: 64,
-        reloading_time: Self::RELOADING_TIME_SMG,
-        partial_reloading: false,
-        grip: WeaponGrip::TwoHandsWithButt,
-        image_offset: 7.0,
-    };
-
-    pub const AKS_74U: Self = Self {
-        name: "AKS-74U",
-        mass: 2.9,
-        level: 4,
-        muzzle_velocity: 735.0,
-        deviation: 0.015,
-        fire_rate: 675.0,
-        is_automatic: true,
-        projectile: &ProjectileConfig::_5_45X39,
-        ammo_capacity: 30,
-        reloading_time: Self::RELOADING_TIME_CARBINE,
-        partial_reloading: false,
-        grip: WeaponGrip::TwoHandsWithButt,
-        image_offset: 8.0,
-    };
-
-    pub const AK_74M: Self = Self {
-        name: "AK-74M",
-        mass: 3.83,
-        level: 4,
-        muzzle_velocity: 910.0,
-        deviation: 0.014,
-        fire_rate: 600.0,
-        is_automatic: true,
-        projectile: &ProjectileConfig::_5_45X39,
-        ammo_capacity: 30,
-        reloading_time: Self::RELOADING_TIME_RIFLE,
-        partial_reloading: false,
-        grip: WeaponGrip::TwoHandsWithButt,
-        image_offset: 9.0,
-    };
-
-    pub const RPK_74: Self = Self {
-        name: "RPK-74",
-        mass: 5.24,
-        level: 5,
-        muzzle_velocity: 960.0,
-        deviation: 0.012,
-        fire_rate: 600.0,
-        is_automatic: true,
-        projectile: &ProjectileConfig::_5_45X39,
-        ammo_capacity: 45,
-        reloading_time: Self::RELOADING_TIME_RIFLE_HEAVY,
-        partial_reloading: false,
-        grip: WeaponGrip::TwoHandsWithButt,
-        image_offset: 9.0,
-    };
-
-    pub const SAIGA_12K: Self = Self {
-        name: "Saiga-12K",
-        mass: 3.3,
-        level: 5,
-        muzzle_velocity: 410.0,
-        deviation: 0.035,
-        fire_rate: Self::SEMI_AUTO_FIRE_RATE,
-        is_automatic: false,
-        projectile: &ProjectileConfig::_12X76,
-        ammo_capacity: 8,
-        reloading_time: Self::RELOADING_TIME_RIFLE,
-        partial_reloading: false,
-        grip: WeaponGrip::TwoHandsWithButt,
-        image_offset: 9.0,
-    };
-
-    pub const PKM: Self = Self {
-        name: "PKM",
-        mass: 7.5,
-        level: 6,
-        muzzle_velocity: 825.0,
-        deviation: 0.011,
-        fire_rate: 650.0,
-        is_automatic: true,
-        projectile: &ProjectileConfig::_7_62X54,
-        ammo_capacity: 100,
-        reloading_time: Self::RELOADING_TIME_MACHINE_GUN,
-        partial_reloading: false,
-        grip: WeaponGrip::TwoHandsWithButt,
-        image_offset: 10.0,
-    };
-
-    pub const PKP_PECHENEG: Self = Self {
-        name: "PKP Pecheneg",
-        mass: 8.2,
-        level: 6,
-        muzzle_velocity: 825.0,
-        deviation: 0.01,
-        fire_rate: 650.0,
-        is_automatic: true,
-        projectile: &ProjectileConfig::_7_62X54,
-        ammo_capacity: 100,
-        reloading_time: Self::RELOADING_TIME_MACHINE_GUN,
-        partial_reloading: false,
-        grip: WeaponGrip::TwoHandsWithButt,
-        image_offset: 10.0,
-    };
+    const SWITCHING_TIME_BASE: Duration = Duration::from_millis(200);
+    const SWITCHING_TIME_PER_MASS: Duration = Duration::from_millis(120);
 
-    pub fn generate_velocity(&self, rng: &mut Pcg32) -> f32 {
-        let deviation = rng.gen_normal(self.muzzle_velocity * Self::VELOCITY_DEVIATION);
-        return self.muzzle_velocity + deviation;
+    pub fn get_round_mass(&self) -> f32 {
+        return self.projectile.as_ref().map_or(0.0, |p| p.mass);
     }
 
-    pub fn generate_deviation(&self, rng: &mut Pcg32) -> f32 {
-        return rng.gen_normal(self.deviation);
+    /// Momentum of a strike with the weapon stock. Heavier weapons hit harder
+    pub fn get_bash_damage(&self) -> f32 {
+        return Self::BASH_DAMAGE_BASE + Self::BASH_DAMAGE_PER_MASS * self.mass;
     }
 
-    pub fn get_mass_with_full_ammo(&self) -> f32 {
-        return self.mass + self.projectile.mass * f32::from(self.ammo_capacity);
+    /// Time to recover after a bash. Heavier weapons are slower
+    pub fn get_bash_interval(&self) -> Duration {
+        return Self::BASH_INTERVAL_BASE + Self::BASH_INTERVAL_PER_MASS.mul_f32(self.mass);
     }
 
-    pub fn get_image_path(&self) -> String {
-        return format!("weapons/{}/image.png", self.name);
+    /// Time to either draw or holster the weapon
+    pub fn get_switching_time(&self) -> Duration {
+        return Self::SWITCHING_TIME_BASE + Self::SWITCHING_TIME_PER_MASS.mul_f32(self.mass);
     }
 
-    pub fn get_ammo_normalized(&self, ammo: u8) -> f32 {
-        if self.ammo_capacity == 0 {
-            return 1.0;
-        } else {
-            return f32::from(ammo) / f32::from(self.ammo_capacity);
-        }
+    pub fn get_image_path(&self) -> String {
+        return format!("weapons/{}/image.png", self.name);
     }
 }
 
 impl Weapon {
-    pub const fn new(config: &'static WeaponConfig) -> Self {
+    pub fn new(config: Arc<WeaponConfig>) -> Self {
         return Self {
+            ammo: config.ammo_capacity,
             config,
+            attachments: WeaponAttachments::EMPTY,
             is_armed: true,
             is_cocked: true,
             is_trigger_pressed: false,
             reloading: None,
-            ammo: config.ammo_capacity,
+            fire_mode: 0,
+            burst_left: 0,
             next_time: Duration::from_secs(0),
+            heat: 0.0,
+            heat_time: Duration::ZERO,
+            is_overheated: false,
+            bloom: 0.0,
         };
     }
 
     pub fn fire(&mut self, time: Duration) -> WeaponFireResult {
-        if !self.config.is_automatic && self.is_trigger_pressed {
-            return WeaponFireResult::NotReady;
+        match self.get_fire_mode() {
+            FireMode::Semi => {
+                if self.is_trigger_pressed {
+                    return WeaponFireResult::NotReady;
+                }
+            }
+            FireMode::Burst => {
+                // a started burst goes on even if the trigger is released
+                if self.burst_left == 0 {
+                    if self.is_trigger_pressed {
+                        return WeaponFireResult::NotReady;
+                    }
+
+                    self.burst_left = self.config.burst_size;
+                }
+            }
+            FireMode::Auto => {}
         }
 
         self.is_trigger_pressed = true;
 
         if self.is_ready(time) {
+            if self.is_overheated(time) {
+                self.burst_left = 0;
+                return WeaponFireResult::Overheated;
+            }
+
             self.next_time = time + Duration::from_secs_f32(60.0 / self.config.fire_rate);
+            self.heat_up(time);
 
-            if self.config.ammo_capacity == 0 {
+            if self.get_ammo_capacity() == 0 {
+                self.burst_left = self.burst_left.saturating_sub(1);
                 return WeaponFireResult::Fire;
             } else if self.ammo > 0 {
                 self.ammo -= 1;
+                self.burst_left = self.burst_left.saturating_sub(1);
                 return WeaponFireResult::Fire;
             } else {
                 if !self.config.partial_reloading {
@@ -322,6 +191,7 @@ impl Weapon {
                 }
 
                 self.is_cocked = false;
+                self.burst_left = 0;
                 return WeaponFireResult::Empty;
             }
         } else {
@@ -329,37 +199,86 @@ impl Weapon {
         }
     }
 
+    fn heat_up(&mut self, time: Duration) {
+        let Some(heat_per_shot) = self.config.hitscan.as_ref().map(|h| h.heat_per_shot) else {
+            return;
+        };
+
+        self.heat = self.get_heat(time) + heat_per_shot;
+        self.heat_time = time;
+        self.is_overheated = self.heat >= 1.0;
+    }
+
     pub fn release_trigger(&mut self) {
         self.is_trigger_pressed = false;
     }
 
-    pub fn reload(&mut self, time: Duration, duration: Duration) {
-        if self.reloading.is_none() {
-            if self.config.partial_reloading {
-                if self.ammo == self.config.ammo_capacity {
-                    self.ammo = self.ammo.saturating_sub(1);
-                }
-            } else {
-                self.ammo = 0;
+    /// Releases the trigger and cuts the burst short
+    fn stop_firing(&mut self) {
+        self.release_trigger();
+        self.burst_left = 0;
+    }
+
+    pub fn cycle_fire_mode(&mut self) -> FireMode {
+        self.fire_mode = (self.fire_mode + 1) % self.config.fire_modes.len().max(1);
+        self.stop_firing();
+        return self.get_fire_mode();
+    }
+
+    /// Switches to the given fire mode. Returns false if the weapon doesn't support it
+    pub fn set_fire_mode(&mut self, mode: FireMode) -> bool {
+        let Some(i) = self.config.fire_modes.iter().position(|m| *m == mode) else {
+            return false;
+        };
+
+        if i != self.fire_mode {
+            self.fire_mode = i;
+            self.stop_firing();
+        }
+
+        return true;
+    }
+
+    /// Starts reloading and returns the number of unloaded rounds
+    pub fn reload(&mut self, time: Duration, duration: Duration) -> u8 {
+        if self.reloading.is_some() {
+            return 0;
+        }
+
+        let ammo = self.ammo;
+        self.burst_left = 0;
+
+        if self.config.partial_reloading {
+            if self.ammo == self.get_ammo_capacity() {
+                self.ammo = self.ammo.saturating_sub(1);
             }
+        } else {
+            self.ammo = 0;
+        }
+
+        self.reloading = Some(duration);
+        self.next_time = time + duration;
+
+        return ammo - self.ammo;
+    }
 
-            self.reloading = Some(duration);
-            self.next_time = time + duration;
+    /// Number of rounds to load once the reloading completes
+    pub fn get_reloading_demand(&self) -> u8 {
+        let missing = self.get_ammo_capacity().saturating_sub(self.ammo);
+
+        if self.config.partial_reloading {
+            return u8::min(missing, 1);
+        } else {
+            return missing;
         }
     }
 
-    pub fn complete_reloading(&mut self, time: Duration) {
+    /// Completes reloading with `loaded` rounds taken from the reserve
+    pub fn complete_reloading(&mut self, time: Duration, loaded: u8) {
         if self.reloading.is_some() {
             self.is_cocked = true;
             self.reloading = None;
-
-            if self.config.partial_reloading {
-                if self.ammo < self.config.ammo_capacity {
-                    self.ammo += 1;
-                }
-            } else {
-                self.ammo = self.config.ammo_capacity;
-            }
+            self.ammo = u8::min(self.ammo.saturating_add(loaded), self.get_ammo_capacity());
 
             if !self.is_armed {
                 self.is_armed = true;
@@ -368,12 +287,114 @@ impl Weapon {
         }
     }
 
+    /// Interrupts reloading. Rounds unloaded before are already back in the reserve
+    pub fn holster(&mut self) {
+        self.reloading = None;
+        self.stop_firing();
+    }
+
+    pub fn draw(&mut self, time: Duration, duration: Duration) {
+        self.next_time = time + duration;
+    }
+
+    /// Whether the attachment fits the weapon and isn't mounted yet
+    pub fn can_attach(&self, attachment: WeaponAttachment) -> bool {
+        return self.config.attachments.contains(attachment)
+            && !self.attachments.contains(attachment);
+    }
+
+    /// Mounts the attachment. Returns false if it can't be mounted
+    pub fn attach(&mut self, attachment: WeaponAttachment) -> bool {
+        if !self.can_attach(attachment) {
+            return false;
+        }
+
+        self.attachments.insert(attachment);
+        return true;
+    }
+
+    pub fn has_attachment(&self, attachment: WeaponAttachment) -> bool {
+        return self.attachments.contains(attachment);
+    }
+
+    pub fn get_attachments(&self) -> WeaponAttachments {
+        return self.attachments;
+    }
+
+    pub fn generate_velocity(&self, rng: &mut Pcg32) -> f32 {
+        let mut velocity = self.config.muzzle_velocity;
+
+        if self.has_attachment(WeaponAttachment::Suppressor) {
+            velocity *= WeaponAttachment::SUPPRESSOR_VELOCITY;
+        }
+
+        return velocity + rng.gen_normal(velocity * WeaponConfig::VELOCITY_DEVIATION);
+    }
+
+    pub fn generate_deviation(&self, rng: &mut Pcg32, is_aiming: bool) -> f32 {
+        let mut deviation = self.config.deviation;
+
+        if is_aiming {
+            deviation *= AIM_DEVIATION;
+        }
+
+        if self.has_attachment(WeaponAttachment::Scope) {
+            deviation *= WeaponAttachment::SCOPE_DEVIATION;
+        }
+
+        return rng.gen_normal(deviation + self.bloom);
+    }
+
+    /// Widens the spread after a shot with the given recoil
+    pub fn add_bloom(&mut self, recoil: f32) {
+        self.bloom = f32::min(self.bloom + recoil.abs() * BLOOM_PER_RECOIL, BLOOM_MAX);
+    }
+
+    /// Narrows the spread back over time. Higher steadiness recovers faster
+    pub fn recover_bloom(&mut self, delta: f32, steadiness: f32) {
+        self.bloom -= self.bloom * BLOOM_RECOVERY.delta(delta * steadiness);
+    }
+
+    /// Extra spread from sustained fire, radians
+    pub fn get_bloom(&self) -> f32 {
+        return self.bloom;
+    }
+
+    /// Loudness of a shot relative to the weapon without a suppressor
+    pub fn get_noise(&self) -> f32 {
+        if self.has_attachment(WeaponAttachment::Suppressor) {
+            return WeaponAttachment::SUPPRESSOR_NOISE;
+        } else {
+            return 1.0;
+        }
+    }
+
+    pub fn get_ammo_capacity(&self) -> u8 {
+        let capacity = self.config.ammo_capacity;
+
+        if self.has_attachment(WeaponAttachment::ExtendedMagazine) {
+            return (f32::from(capacity) * WeaponAttachment::EXTENDED_MAGAZINE_CAPACITY).round()
+                as u8;
+        } else {
+            return capacity;
+        }
+    }
+
     pub fn get_mass(&self) -> f32 {
-        return self.config.mass + self.config.projectile.mass * f32::from(self.ammo);
+        return self.get_mass_empty() + self.config.get_round_mass() * f32::from(self.ammo);
+    }
+
+    pub fn get_mass_with_full_ammo(&self) -> f32 {
+        return self.get_mass_empty()
+            + self.config.get_round_mass() * f32::from(self.get_ammo_capacity());
+    }
+
+    fn get_mass_empty(&self) -> f32 {
+        return self.config.mass + self.attachments.iter().map(|a| a.mass()).sum::<f32>();
     }
 
     pub fn get_recoil(&self) -> f32 {
-        let momentum = self.config.muzzle_velocity * self.config.projectile.mass;
+        let momentum = self.config.muzzle_velocity * self.config.get_round_mass();
         let mass = self.get_mass().powf(WeaponConfig::RECOIL_MASS_POW);
 
         return (momentum / mass).powf(WeaponConfig::RECOIL_POW)
@@ -382,6 +403,10 @@ impl Weapon {
     }
 
     pub fn get_ammo_normalized(&self, time: Duration) -> f32 {
+        if self.config.hitscan.is_some() {
+            return 1.0 - self.get_heat(time);
+        }
+
         if let Some(reloading_duration) = self.reloading {
             let progress = time.progress(
                 self.next_time.saturating_sub(reloading_duration),
@@ -390,18 +415,47 @@ impl Weapon {
 
             if self.config.partial_reloading {
                 return interpolate(
-                    self.config.get_ammo_normalized(self.ammo),
-                    self.config.get_ammo_normalized(self.ammo + 1),
+                    self.normalize_ammo(self.ammo),
+                    self.normalize_ammo(self.ammo + 1),
                     progress,
                 );
             } else {
                 return progress;
             }
         } else {
-            return self.config.get_ammo_normalized(self.ammo);
+            return self.normalize_ammo(self.ammo);
         }
     }
 
+    fn normalize_ammo(&self, ammo: u8) -> f32 {
+        let capacity = self.get_ammo_capacity();
+
+        if capacity == 0 {
+            return 1.0;
+        } else {
+            return f32::from(ammo) / f32::from(capacity);
+        }
+    }
+
+    /// Heat of a hitscan weapon, from 0.0 to 1.0. Always zero for other weapons
+    pub fn get_heat(&self, time: Duration) -> f32 {
+        let Some(hitscan) = self.config.hitscan.as_ref() else {
+            return 0.0;
+        };
+
+        let cooled = time.saturating_sub(self.heat_time).as_secs_f32() * hitscan.cooling;
+        return f32::clamp(self.heat - cooled, 0.0, 1.0);
+    }
+
+    pub fn get_fire_mode(&self) -> FireMode {
+        return self
+            .config
+            .fire_modes
+            .get(self.fire_mode)
+            .copied()
+            .unwrap_or(FireMode::Semi);
+    }
+
     pub fn is_cocked(&self) -> bool {
         return self.is_cocked;
     }
@@ -410,6 +464,11 @@ impl Weapon {
         return self.is_trigger_pressed;
     }
 
+    /// Whether a burst has started and has rounds left to fire
+    pub fn is_bursting(&self) -> bool {
+        return self.burst_left > 0;
+    }
+
     pub fn is_armed(&self) -> bool {
         return self.is_armed;
     }
@@ -418,6 +477,11 @@ impl Weapon {
         return self.reloading.is_some();
     }
 
+    /// Whether the weapon has overheated and hasn't cooled down enough yet
+    pub fn is_overheated(&self, time: Duration) -> bool {
+        return self.is_overheated && self.get_heat(time) > OVERHEAT_RECOVERY;
+    }
+
     pub fn is_ready(&self, time: Duration) -> bool {
         return self.next_time < time;
     }
@@ -426,9 +490,40 @@ impl Weapon {
 pub enum WeaponFireResult {
     NotReady,
     Empty,
+    Overheated,
     Fire,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FireMode {
+    Semi,
+    /// Fires `WeaponConfig::burst_size` rounds per trigger pull, even if the trigger is released
+    /// earlier
+    Burst,
+    Auto,
+}
+
+impl FireMode {
+    pub fn is_continuous(self) -> bool {
+        return match self {
+            Self::Semi => false,
+            Self::Burst => true,
+            Self::Auto => true,
+        };
+    }
+
+    pub fn name(self) -> &'static str {
+        return match self {
+            Self::Semi => "semi-auto",
+            Self::Burst => "burst",
+            Self::Auto => "auto",
+        };
+    }
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum WeaponGrip {
     OneHand,
     TwoHands,
@@ -452,3 +547,122 @@ impl WeaponGrip {
         };
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> WeaponConfig {
+        return config_with(vec![FireMode::Auto]);
+    }
+
+    fn config_with(fire_modes: Vec<FireMode>) -> WeaponConfig {
+        return WeaponConfig {
+            id: "test".to_string(),
+            name: "Test".to_string(),
+            mass: 5.0,
+            level: 1,
+            muzzle_velocity: 900.0,
+            deviation: 0.01,
+            fire_rate: 600.0,
+            fire_modes,
+            burst_size: 3,
+            projectile: Some(Arc::new(ProjectileConfig {
+                caliber: "5.45x39".to_string(),
+                fragments: 1,
+                mass: 0.0034,
+                size: 1.0,
+                penetration: 0.0,
+                armor_piercing: 0.0,
+            })),
+            hitscan: None,
+            melee: None,
+            ammo_capacity: 45,
+            reloading_time: Duration::from_secs(3),
+            partial_reloading: false,
+            aim_zoom: 0.0,
+            attachments: WeaponAttachments::EMPTY,
+            grip: WeaponGrip::TwoHandsWithButt,
+            image_offset: 0.0,
+        };
+    }
+
+    #[test]
+    fn test_bloom_accumulates() {
+        let mut weapon = Weapon::new(Arc::new(config()));
+        let recoil = weapon.get_recoil();
+
+        weapon.add_bloom(recoil);
+        let single = weapon.get_bloom();
+
+        for _ in 0..9 {
+            weapon.add_bloom(recoil);
+        }
+
+        assert!(single > 0.0);
+        assert!(weapon.get_bloom() > single * 5.0);
+        assert!(weapon.get_bloom() <= BLOOM_MAX);
+    }
+
+    #[test]
+    fn test_bloom_recovers() {
+        let mut weapon = Weapon::new(Arc::new(config()));
+        weapon.add_bloom(weapon.get_recoil());
+        let initial = weapon.get_bloom();
+
+        let mut steady = Weapon::new(Arc::new(config()));
+        steady.add_bloom(steady.get_recoil());
+
+        for _ in 0..10 {
+            weapon.recover_bloom(0.01, 1.0);
+            steady.recover_bloom(0.01, 2.0);
+        }
+
+        assert!(weapon.get_bloom() < initial);
+        assert!(steady.get_bloom() < weapon.get_bloom());
+    }
+
+    /// Counts rounds fired over frames, pulling the trigger like the weapon system does
+    fn count_shots(mode: FireMode, trigger: &[bool]) -> usize {
+        let mut weapon = Weapon::new(Arc::new(config_with(vec![mode])));
+        let mut shots = 0;
+
+        for (i, is_pressed) in trigger.iter().enumerate() {
+            // 200ms frames, longer than the interval between rounds
+            let time = Duration::from_millis(1000 + 200 * i as u64);
+
+            if !is_pressed {
+                weapon.release_trigger();
+            }
+
+            if (*is_pressed || weapon.is_bursting())
+                && matches!(weapon.fire(time), WeaponFireResult::Fire)
+            {
+                shots += 1;
+            }
+        }
+
+        return shots;
+    }
+
+    #[test]
+    fn test_fire_semi() {
+        assert_eq!(count_shots(FireMode::Semi, &[true, true, true]), 1);
+        assert_eq!(count_shots(FireMode::Semi, &[true, false, true]), 2);
+    }
+
+    #[test]
+    fn test_fire_burst() {
+        let tap = [true, false, false, false, false];
+        let hold = [true; 6];
+        assert_eq!(count_shots(FireMode::Burst, &tap), 3);
+        assert_eq!(count_shots(FireMode::Burst, &hold), 3);
+        assert_eq!(count_shots(FireMode::Burst, &[tap, tap].concat()), 6);
+    }
+
+    #[test]
+    fn test_fire_auto() {
+        assert_eq!(count_shots(FireMode::Auto, &[true; 6]), 6);
+        assert_eq!(count_shots(FireMode::Auto, &[true, false, false, true]), 2);
+    }
+}
@@ -0,0 +1,40 @@
+use enumset::{EnumSet, EnumSetType};
+use serde::Deserialize;
+
+/// Modification mounted on a weapon instance
+#[derive(Debug, Deserialize, EnumSetType)]
+#[serde(rename_all = "snake_case")]
+pub enum WeaponAttachment {
+    Suppressor,
+    Scope,
+    ExtendedMagazine,
+    LaserSight,
+}
+
+pub type WeaponAttachments = EnumSet<WeaponAttachment>;
+
+impl WeaponAttachment {
+    pub const SUPPRESSOR_NOISE: f32 = 0.3;
+    pub const SUPPRESSOR_VELOCITY: f32 = 0.95;
+    pub const SCOPE_DEVIATION: f32 = 0.7;
+    pub const EXTENDED_MAGAZINE_CAPACITY: f32 = 1.5;
+
+    pub fn name(self) -> &'static str {
+        return match self {
+            Self::Suppressor => "Suppressor",
+            Self::Scope => "Scope",
+            Self::ExtendedMagazine => "Extended magazine",
+            Self::LaserSight => "Laser sight",
+        };
+    }
+
+    /// Mass in kg
+    pub fn mass(self) -> f32 {
+        return match self {
+            Self::Suppressor => 0.3,
+            Self::Scope => 0.4,
+            Self::ExtendedMagazine => 0.15,
+            Self::LaserSight => 0.1,
+        };
+    }
+}
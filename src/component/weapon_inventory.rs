@@ -0,0 +1,173 @@
+use super::{Weapon, WeaponConfig, WeaponGrip};
+use bevy::ecs::component::Component;
+use std::time::Duration;
+
+/// Weapons carried by an actor. The weapon in hands is the actor's `Weapon` component,
+/// the inventory keeps only the holstered ones
+#[derive(Component, Default)]
+pub struct WeaponInventory {
+    holstered: [Option<Weapon>; Self::SLOTS],
+    active: Option<usize>,
+    requested: Option<usize>,
+    switching: Option<(usize, Duration)>,
+}
+
+impl WeaponInventory {
+    pub const SLOTS: usize = 2;
+
+    pub fn get_slot(weapon: &WeaponConfig) -> usize {
+        return match weapon.grip {
+            WeaponGrip::OneHand => 0,
+            WeaponGrip::TwoHands | WeaponGrip::TwoHandsWithButt => 1,
+        };
+    }
+
+    pub fn request(&mut self, slot: usize) {
+        if slot < Self::SLOTS {
+            self.requested = Some(slot);
+        }
+    }
+
+    /// Requests the next occupied slot in the given direction
+    pub fn request_next(&mut self, forward: bool) {
+        let Some(active) = self.requested.or(self.active) else {
+            self.requested = self.holstered.iter().position(Option::is_some);
+            return;
+        };
+
+        for i in 1..Self::SLOTS {
+            let slot = if forward {
+                (active + i) % Self::SLOTS
+            } else {
+                (active + Self::SLOTS - i) % Self::SLOTS
+            };
+
+            if self.holstered[slot].is_some() {
+                self.requested = Some(slot);
+                return;
+            }
+        }
+    }
+
+    /// Takes the pending request if the requested weapon can be drawn
+    pub fn take_request(&mut self) -> Option<usize> {
+        let slot = self.requested.take()?;
+
+        if self.is_switching() || Some(slot) == self.active || self.holstered[slot].is_none() {
+            return None;
+        } else {
+            return Some(slot);
+        }
+    }
+
+    pub fn start_switching(&mut self, slot: usize, time: Duration) {
+        self.switching = Some((slot, time));
+    }
+
+    /// Returns the slot to draw once the current weapon has been holstered
+    pub fn take_switched(&mut self, time: Duration) -> Option<usize> {
+        if let Some((slot, switch_time)) = self.switching {
+            if time >= switch_time {
+                self.switching = None;
+                return Some(slot);
+            }
+        }
+
+        return None;
+    }
+
+    /// Puts the weapon into its slot. Returns the weapon which occupied the slot, if any
+    pub fn holster(&mut self, weapon: Weapon) -> Option<Weapon> {
+        let slot = Self::get_slot(&weapon.config);
+
+        if self.active == Some(slot) {
+            self.active = None;
+        }
+
+        return self.holstered[slot].replace(weapon);
+    }
+
+    pub fn draw(&mut self, slot: usize) -> Option<Weapon> {
+        let weapon = self.holstered.get_mut(slot)?.take()?;
+        self.active = Some(slot);
+        return Some(weapon);
+    }
+
+    pub fn set_active(&mut self, slot: Option<usize>) {
+        self.active = slot;
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn get_active(&self) -> Option<usize> {
+        return self.active;
+    }
+
+    pub fn is_switching(&self) -> bool {
+        return self.switching.is_some();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        data::{load_weapons, AMMUNITION_PATH, WEAPONS_PATH},
+        resource::Registry,
+    };
+    use std::sync::Arc;
+
+    fn weapon(weapons: &Registry<WeaponConfig>, id: &str) -> Weapon {
+        return Weapon::new(Arc::clone(weapons.get(id).expect("No weapon")));
+    }
+
+    fn load() -> Registry<WeaponConfig> {
+        return load_weapons(AMMUNITION_PATH, WEAPONS_PATH)
+            .expect("Failed to load weapons")
+            .1;
+    }
+
+    #[test]
+    fn test_slots() {
+        let weapons = load();
+        let mut inventory = WeaponInventory::default();
+
+        assert!(inventory.holster(weapon(&weapons, "pm")).is_none());
+        assert!(inventory.holster(weapon(&weapons, "ak_74m")).is_none());
+
+        let replaced = inventory.holster(weapon(&weapons, "tt"));
+        assert_eq!(replaced.map(|w| w.config.id.clone()), Some("pm".to_owned()));
+
+        assert_eq!(
+            inventory.draw(1).map(|w| w.config.id.clone()),
+            Some("ak_74m".to_owned()),
+        );
+        assert_eq!(inventory.get_active(), Some(1));
+        assert!(inventory.draw(1).is_none());
+    }
+
+    #[test]
+    fn test_switching() {
+        let weapons = load();
+        let mut inventory = WeaponInventory::default();
+        inventory.holster(weapon(&weapons, "ak_74m"));
+        inventory.set_active(Some(0));
+
+        // the active slot and empty slots can't be drawn
+        inventory.request(0);
+        assert_eq!(inventory.take_request(), None);
+
+        inventory.request_next(true);
+        assert_eq!(inventory.take_request(), Some(1));
+
+        let time = Duration::from_secs(1);
+        inventory.start_switching(1, time);
+        inventory.request(1);
+        assert_eq!(inventory.take_request(), None);
+        assert_eq!(inventory.take_switched(Duration::ZERO), None);
+        assert_eq!(inventory.take_switched(time), Some(1));
+        assert!(!inventory.is_switching());
+    }
+}
@@ -0,0 +1,83 @@
+use crate::{component::ActorConfig, data::load_data, resource::Registry};
+use anyhow::{ensure, Context, Result};
+use serde::Deserialize;
+use std::f32::consts::TAU;
+
+#[derive(Deserialize)]
+pub struct ActorsData {
+    pub actors: Vec<ActorConfig>,
+}
+
+impl ActorsData {
+    pub fn into_registry(self) -> Result<Registry<ActorConfig>> {
+        let mut registry = Registry::default();
+
+        for actor in self.actors {
+            validate_actor(&actor).with_context(|| format!("Invalid actor {}", actor.id))?;
+            let id = actor.id.clone();
+            registry.insert(&id, actor)?;
+        }
+
+        return Ok(registry);
+    }
+}
+
+fn validate_actor(actor: &ActorConfig) -> Result<()> {
+    ensure!(!actor.id.is_empty(), "Id is empty");
+    ensure!(
+        actor.movement_velocity >= 0.0,
+        "Movement velocity must not be negative"
+    );
+    ensure!(
+        actor.rotation_velocity > 0.0,
+        "Rotation velocity must be positive"
+    );
+    ensure!(actor.health > 0.0, "Health must be positive");
+    ensure!(actor.radius > 0.0, "Radius must be positive");
+    ensure!(actor.mass > 0.0, "Mass must be positive");
+    ensure!(
+        actor.melee_damage >= 0.0,
+        "Melee damage must not be negative"
+    );
+    ensure!(
+        actor.headshot_threshold.map_or(true, |t| t >= 0.0),
+        "Headshot threshold must not be negative"
+    );
+    ensure!(
+        actor.reloading_speed > 0.0,
+        "Reloading speed must be positive"
+    );
+    ensure!(!actor.images.is_empty(), "At least one image is required");
+    ensure!(
+        actor.bot.view_distance > 0.0,
+        "Bot view distance must be positive"
+    );
+    ensure!(
+        actor.bot.view_angle > 0.0 && actor.bot.view_angle <= TAU,
+        "Bot view angle must be within (0, 2π]"
+    );
+    return Ok(());
+}
+
+pub fn load_actors(path: &str) -> Result<Registry<ActorConfig>> {
+    return load_data::<ActorsData>(path)?.into_registry();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{component::ActorKind, data::ACTORS_PATH};
+
+    #[test]
+    fn test_load_bundled_actors() {
+        let actors = load_actors(ACTORS_PATH).expect("Failed to load actors");
+        let human = actors.get("human").expect("No human archetype");
+        assert_eq!(human.kind, ActorKind::Human);
+        assert!(human.pain_threshold.is_finite());
+
+        let zombie = actors.get("zombie").expect("No zombie archetype");
+        assert_eq!(zombie.kind, ActorKind::Zombie);
+        assert!(zombie.pain_threshold.is_infinite());
+        assert!(zombie.headshot_threshold.is_some());
+    }
+}
@@ -0,0 +1,66 @@
+use crate::{component::ArmorConfig, data::load_data, model::HitZone, resource::Registry};
+use anyhow::{ensure, Context, Result};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct ArmorsData {
+    pub armor: Vec<ArmorData>,
+}
+
+#[derive(Deserialize)]
+pub struct ArmorData {
+    pub id: String,
+    pub name: String,
+    pub class: u8,
+    pub durability: f32,
+    pub coverage: Vec<HitZone>,
+}
+
+impl ArmorsData {
+    pub fn into_registry(self) -> Result<Registry<ArmorConfig>> {
+        let mut registry = Registry::default();
+
+        for armor in self.armor {
+            let id = armor.id.clone();
+            let config = armor
+                .into_config()
+                .with_context(|| format!("Invalid armor {}", id))?;
+
+            registry.insert(&id, config)?;
+        }
+
+        return Ok(registry);
+    }
+}
+
+impl ArmorData {
+    fn into_config(self) -> Result<ArmorConfig> {
+        ensure!(!self.id.is_empty(), "Id is empty");
+        ensure!(self.durability > 0.0, "Durability must be positive");
+        ensure!(!self.coverage.is_empty(), "Coverage is empty");
+
+        return Ok(ArmorConfig {
+            name: self.name,
+            class: self.class,
+            durability: self.durability,
+            coverage: self.coverage.into_iter().collect(),
+        });
+    }
+}
+
+pub fn load_armor(path: &str) -> Result<Registry<ArmorConfig>> {
+    return load_data::<ArmorsData>(path)?.into_registry();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::ARMOR_PATH;
+
+    #[test]
+    fn test_load_bundled_armor() {
+        let armor = load_armor(ARMOR_PATH).expect("Failed to load armor");
+        let vest = armor.get("vest").expect("No vest");
+        assert!(vest.coverage.contains(HitZone::Torso));
+    }
+}
@@ -1,20 +1,31 @@
-use crate::util::ext::RngExt;
+use crate::{data::deserialize_seconds, util::ext::RngExt};
 use rand::Rng;
+use serde::Deserialize;
 use std::{f32::consts::FRAC_PI_4, time::Duration};
 
+#[derive(Deserialize)]
 pub struct BotConfig {
     pub is_silly: bool,
     pub is_agile: bool,
+    #[serde(deserialize_with = "deserialize_seconds")]
     pub reaction: Duration,
     pub spread: f32,
     pub spread_force: f32,
     pub sprint_distance: f32,
     pub shoot_distance_min: f32,
     pub shoot_distance_max: f32,
+    pub auto_fire_distance: f32,
     pub angular_deviation: f32,
+    #[serde(deserialize_with = "deserialize_seconds")]
     pub shoot_prepare_duration: Duration,
+    #[serde(deserialize_with = "deserialize_seconds")]
     pub shoot_burst_duration: Duration,
+    #[serde(deserialize_with = "deserialize_seconds")]
     pub shoot_interval: Duration,
+    /// How far enemies can be spotted
+    pub view_distance: f32,
+    /// Full angle of the view cone
+    pub view_angle: f32,
 }
 
 impl BotConfig {
@@ -22,44 +33,21 @@ impl BotConfig {
     pub const IDLE_MOVEMENT_CHANCE: f64 = 0.1;
     pub const REPEAT_SHOOT_CHANCE: f64 = 0.6;
     pub const DODGE_ANGLE: f32 = FRAC_PI_4;
-
-    pub const HUMAN: &'static Self = &Self {
-        is_silly: false,
-        is_agile: false,
-        reaction: Duration::from_millis(250),
-        spread: 0.8,
-        spread_force: 0.2,
-        sprint_distance: 12.0,
-        shoot_distance_min: 6.0,
-        shoot_distance_max: 20.0,
-        angular_deviation: 0.05,
-        shoot_prepare_duration: Duration::from_millis(800),
-        shoot_burst_duration: Duration::from_millis(400),
-        shoot_interval: Duration::from_millis(300),
-    };
-
-    pub const ZOMBIE: &'static Self = &Self {
-        is_silly: true,
-        is_agile: false,
-        reaction: Duration::from_millis(500),
-        spread: 3.0,
-        spread_force: 0.4,
-        sprint_distance: Self::HUMAN.sprint_distance,
-        shoot_distance_min: 3.0,
-        shoot_distance_max: 10.0,
-        angular_deviation: 0.16,
-        shoot_prepare_duration: Duration::from_millis(1500),
-        shoot_burst_duration: Duration::from_millis(400),
-        shoot_interval: Duration::from_millis(800),
-    };
-
-    pub const ZOMBIE_AGILE: &'static Self = &Self {
-        is_silly: true,
-        is_agile: true,
-        reaction: Self::HUMAN.reaction,
-        spread: 0.8,
-        ..*Self::ZOMBIE
-    };
+    pub const GRENADE_GROUP_MIN: usize = 3;
+    pub const GRENADE_GROUP_RADIUS: f32 = 3.0;
+    pub const LASER_SIGHT_AIM_FACTOR: f32 = 0.7;
+    /// Enemies this close are noticed regardless of the view cone
+    pub const SENSE_DISTANCE: f32 = 1.5;
+    /// How long to look for a lost enemy before giving up
+    pub const SEARCH_DURATION: Duration = Duration::from_secs(30);
+    /// How close to the last known enemy position the bot has to come to consider it checked
+    pub const SEARCH_REACH: f32 = 1.0;
+    /// How often to look for a better path while following one
+    pub const REPATH_INTERVAL: Duration = Duration::from_millis(500);
+    /// How far the goal can move before the path is searched again
+    pub const REPATH_DISTANCE: f32 = 1.0;
+    /// How far ahead an idle bot plans its walk
+    pub const IDLE_WALK_DISTANCE: f32 = 4.0;
 
     pub fn clone_with<R: Rng>(&self, skill: f32, r: &mut R) -> Self {
         return Self {
@@ -71,10 +59,13 @@ impl BotConfig {
             sprint_distance: r.fuzz(self.sprint_distance),
             shoot_distance_min: r.fuzz(self.shoot_distance_min),
             shoot_distance_max: r.fuzz(self.shoot_distance_max) * skill,
+            auto_fire_distance: r.fuzz(self.auto_fire_distance),
             angular_deviation: self.angular_deviation / skill,
             shoot_prepare_duration: self.shoot_prepare_duration.div_f32(skill),
             shoot_burst_duration: self.shoot_burst_duration,
             shoot_interval: self.shoot_interval.div_f32(skill),
+            view_distance: r.fuzz(self.view_distance) * skill,
+            view_angle: self.view_angle,
         };
     }
 }
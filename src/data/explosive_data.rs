@@ -0,0 +1,102 @@
+use crate::{
+    component::{ExplosiveConfig, ProjectileConfig},
+    data::{deserialize_seconds, load_data},
+    resource::Registry,
+};
+use anyhow::{ensure, Context, Result};
+use serde::Deserialize;
+use std::time::Duration;
+
+#[derive(Deserialize)]
+pub struct ExplosivesData {
+    pub explosives: Vec<ExplosiveData>,
+}
+
+#[derive(Deserialize)]
+pub struct ExplosiveData {
+    pub id: String,
+    pub name: String,
+    #[serde(deserialize_with = "deserialize_seconds")]
+    pub fuse: Duration,
+    pub throw_velocity: f32,
+    pub radius: f32,
+    pub momentum: f32,
+    pub fragments: u8,
+    pub fragment: String,
+    pub fragment_velocity: f32,
+    #[serde(default)]
+    pub burning: f32,
+}
+
+impl ExplosivesData {
+    pub fn into_registry(
+        self,
+        ammunition: &Registry<ProjectileConfig>,
+    ) -> Result<Registry<ExplosiveConfig>> {
+        let mut registry = Registry::default();
+
+        for explosive in self.explosives {
+            let id = explosive.id.clone();
+            let config = explosive
+                .into_config(ammunition)
+                .with_context(|| format!("Invalid explosive {}", id))?;
+
+            registry.insert(&id, config)?;
+        }
+
+        return Ok(registry);
+    }
+}
+
+impl ExplosiveData {
+    fn into_config(self, ammunition: &Registry<ProjectileConfig>) -> Result<ExplosiveConfig> {
+        ensure!(!self.id.is_empty(), "Id is empty");
+        ensure!(self.throw_velocity > 0.0, "Throw velocity must be positive");
+        ensure!(self.radius > 0.0, "Radius must be positive");
+        ensure!(self.momentum >= 0.0, "Momentum must not be negative");
+        ensure!(
+            self.fragment_velocity >= 0.0,
+            "Fragment velocity must not be negative"
+        );
+        ensure!(self.burning >= 0.0, "Burning must not be negative");
+
+        let fragment = ammunition
+            .get(&self.fragment)
+            .cloned()
+            .with_context(|| format!("Unknown fragment {}", self.fragment))?;
+
+        return Ok(ExplosiveConfig {
+            name: self.name,
+            fuse: self.fuse,
+            throw_velocity: self.throw_velocity,
+            radius: self.radius,
+            momentum: self.momentum,
+            fragments: self.fragments,
+            fragment,
+            fragment_velocity: self.fragment_velocity,
+            burning: self.burning,
+        });
+    }
+}
+
+pub fn load_explosives(
+    path: &str,
+    ammunition: &Registry<ProjectileConfig>,
+) -> Result<Registry<ExplosiveConfig>> {
+    return load_data::<ExplosivesData>(path)?.into_registry(ammunition);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{load_weapons, AMMUNITION_PATH, EXPLOSIVES_PATH, WEAPONS_PATH};
+
+    #[test]
+    fn test_load_bundled_explosives() {
+        let (ammunition, _) =
+            load_weapons(AMMUNITION_PATH, WEAPONS_PATH).expect("Failed to load weapons");
+        let explosives =
+            load_explosives(EXPLOSIVES_PATH, &ammunition).expect("Failed to load explosives");
+        assert!(!explosives.is_empty());
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{
+    data::load_data,
+    model::{Faction, Relationship},
+    resource::Factions,
+};
+use anyhow::{ensure, Result};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct FactionsData {
+    pub relationships: Vec<RelationshipData>,
+}
+
+#[derive(Deserialize)]
+pub struct RelationshipData {
+    pub factions: [Faction; 2],
+    pub relationship: Relationship,
+}
+
+impl FactionsData {
+    pub fn into_factions(self) -> Result<Factions> {
+        let mut factions = Factions::default();
+        let mut defined = Vec::with_capacity(self.relationships.len());
+
+        for relationship in self.relationships {
+            let [a, b] = relationship.factions;
+            ensure!(a != b, "Relationship of {:?} with itself", a);
+            ensure!(
+                !defined.contains(&(a, b)) && !defined.contains(&(b, a)),
+                "Duplicate relationship of {:?} and {:?}",
+                a,
+                b,
+            );
+
+            factions.set(a, b, relationship.relationship);
+            defined.push((a, b));
+        }
+
+        return Ok(factions);
+    }
+}
+
+pub fn load_factions(path: &str) -> Result<Factions> {
+    return load_data::<FactionsData>(path)?.into_factions();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{parse_data_str, FACTIONS_PATH};
+
+    #[test]
+    fn test_load_bundled_factions() {
+        let factions = load_factions(FACTIONS_PATH).expect("Failed to load factions");
+        assert!(factions.is_hostile(Faction::Loners, Faction::Zombies));
+        assert!(factions.is_hostile(Faction::Military, Faction::Zombies));
+    }
+
+    #[test]
+    fn test_duplicate_relationship() {
+        let factions = parse_data_str::<FactionsData>(
+            r#"
+            [[relationships]]
+            factions = ["loners", "bandits"]
+            relationship = "hostile"
+
+            [[relationships]]
+            factions = ["bandits", "loners"]
+            relationship = "neutral"
+            "#,
+        )
+        .expect("Failed to parse factions");
+
+        assert!(factions.into_factions().is_err());
+    }
+}
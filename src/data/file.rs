@@ -0,0 +1,34 @@
+use anyhow::{Context, Result};
+use config::{File, Source};
+use serde::{
+    de::{DeserializeOwned, Error},
+    Deserialize, Deserializer,
+};
+use std::time::Duration;
+
+pub fn load_data<T: DeserializeOwned>(path: &str) -> Result<T> {
+    return parse_data(File::with_name(path))
+        .with_context(|| format!("Failed to load data from {}", path));
+}
+
+#[cfg(test)]
+pub fn parse_data_str<T: DeserializeOwned>(data: &str) -> Result<T> {
+    use config::FileFormat;
+
+    return parse_data(File::from_str(data, FileFormat::Toml));
+}
+
+fn parse_data<T: DeserializeOwned, S: Source + Send + Sync + 'static>(source: S) -> Result<T> {
+    return Ok(config::Config::builder()
+        .add_source(source)
+        .build()?
+        .try_deserialize::<T>()?);
+}
+
+/// Reads a duration written in seconds
+pub fn deserialize_seconds<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Duration, D::Error> {
+    let seconds = f32::deserialize(deserializer)?;
+    return Duration::try_from_secs_f32(seconds).map_err(D::Error::custom);
+}
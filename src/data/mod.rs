@@ -1,10 +1,25 @@
+mod actor_data;
+mod armor_data;
 mod bot_config;
+mod explosive_data;
+mod faction_data;
+mod file;
+mod weapon_data;
 
-pub use self::bot_config::*;
+pub use self::{
+    actor_data::*, armor_data::*, bot_config::*, explosive_data::*, faction_data::*, file::*,
+    weapon_data::*,
+};
 use bevy::prelude::Vec3;
 
 pub const APP_TITLE: &str = "A Zombie Shooter Game";
 pub const CONFIG_PATH: &str = "./config.toml";
+pub const ACTORS_PATH: &str = "./assets/data/actors.toml";
+pub const ARMOR_PATH: &str = "./assets/data/armor.toml";
+pub const AMMUNITION_PATH: &str = "./assets/data/ammunition.toml";
+pub const FACTIONS_PATH: &str = "./assets/data/factions.toml";
+pub const EXPLOSIVES_PATH: &str = "./assets/data/explosives.toml";
+pub const WEAPONS_PATH: &str = "./assets/data/weapons.toml";
 
 pub const PIXELS_PER_METER: f32 = 32.0;
 pub const VIEW_DISTANCE: f32 = 37.5;
@@ -20,6 +35,7 @@ pub const LAYER_ACTOR_PLAYER: f32 = 0.3;
 pub const LAYER_BONUS: f32 = 0.4;
 pub const LAYER_PROJECTILE: f32 = 0.5;
 pub const LAYER_TREE: f32 = 0.6;
+pub const LAYER_IMPACT: f32 = 0.7;
 
 pub const TRANSFORM_SCALE: Vec3 = Vec3::splat(1.0 / PIXELS_PER_METER);
 
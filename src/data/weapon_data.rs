@@ -0,0 +1,283 @@
+use crate::{
+    component::{
+        FireMode, HitscanConfig, MeleeConfig, ProjectileConfig, WeaponAttachment, WeaponConfig,
+        WeaponGrip,
+    },
+    data::{deserialize_seconds, load_data},
+    resource::Registry,
+};
+use anyhow::{ensure, Context, Result};
+use serde::Deserialize;
+use std::time::Duration;
+
+#[derive(Deserialize)]
+pub struct AmmunitionData {
+    pub ammunition: Vec<ProjectileConfig>,
+}
+
+#[derive(Deserialize)]
+pub struct WeaponsData {
+    pub weapons: Vec<WeaponData>,
+}
+
+#[derive(Deserialize)]
+pub struct WeaponData {
+    pub id: String,
+    pub name: String,
+    pub mass: f32,
+    pub level: u8,
+    #[serde(default)]
+    pub muzzle_velocity: f32,
+    pub deviation: f32,
+    pub fire_rate: f32,
+    pub fire_modes: Vec<FireMode>,
+    #[serde(default)]
+    pub burst_size: u8,
+    #[serde(default)]
+    pub projectile: Option<String>,
+    #[serde(default)]
+    pub hitscan: Option<HitscanConfig>,
+    #[serde(default)]
+    pub melee: Option<MeleeConfig>,
+    #[serde(default)]
+    pub ammo_capacity: u8,
+    #[serde(default, deserialize_with = "deserialize_seconds")]
+    pub reloading_time: Duration,
+    #[serde(default)]
+    pub partial_reloading: bool,
+    #[serde(default)]
+    pub aim_zoom: f32,
+    #[serde(default)]
+    pub attachments: Vec<WeaponAttachment>,
+    pub grip: WeaponGrip,
+    pub image_offset: f32,
+}
+
+impl AmmunitionData {
+    pub fn into_registry(self) -> Result<Registry<ProjectileConfig>> {
+        let mut registry = Registry::default();
+
+        for projectile in self.ammunition {
+            validate_projectile(&projectile)
+                .with_context(|| format!("Invalid ammunition {}", projectile.caliber))?;
+
+            let caliber = projectile.caliber.clone();
+            registry.insert(&caliber, projectile)?;
+        }
+
+        return Ok(registry);
+    }
+}
+
+impl WeaponsData {
+    pub fn into_registry(
+        self,
+        ammunition: &Registry<ProjectileConfig>,
+    ) -> Result<Registry<WeaponConfig>> {
+        let mut registry = Registry::default();
+
+        for weapon in self.weapons {
+            let id = weapon.id.clone();
+            let config = weapon
+                .into_config(ammunition)
+                .with_context(|| format!("Invalid weapon {}", id))?;
+
+            registry.insert(&id, config)?;
+        }
+
+        return Ok(registry);
+    }
+}
+
+impl WeaponData {
+    fn into_config(self, ammunition: &Registry<ProjectileConfig>) -> Result<WeaponConfig> {
+        ensure!(!self.id.is_empty(), "Id is empty");
+        ensure!(self.mass > 0.0, "Mass must be positive");
+        ensure!(self.deviation >= 0.0, "Deviation must not be negative");
+        ensure!(self.fire_rate > 0.0, "Fire rate must be positive");
+        ensure!(self.aim_zoom >= 0.0, "Aim zoom must not be negative");
+        ensure!(!self.fire_modes.is_empty(), "Fire modes are empty");
+        ensure!(
+            self.burst_size > 0 || !self.fire_modes.contains(&FireMode::Burst),
+            "Burst size must be positive for the burst fire mode",
+        );
+
+        let kinds = [
+            self.projectile.is_some(),
+            self.hitscan.is_some(),
+            self.melee.is_some(),
+        ];
+
+        ensure!(
+            kinds.iter().filter(|k| **k).count() == 1,
+            "Exactly one of projectile, hitscan or melee must be set",
+        );
+
+        if let Some(melee) = &self.melee {
+            ensure!(melee.damage >= 0.0, "Damage must not be negative");
+            ensure!(melee.reach > 0.0, "Reach must be positive");
+            ensure!(melee.arc > 0.0, "Arc must be positive");
+            ensure!(!melee.swing_time.is_zero(), "Swing time must be positive");
+        } else if let Some(hitscan) = &self.hitscan {
+            ensure!(hitscan.momentum >= 0.0, "Momentum must not be negative");
+            ensure!(hitscan.range > 0.0, "Range must be positive");
+            ensure!(
+                hitscan.heat_per_shot > 0.0,
+                "Heat per shot must be positive"
+            );
+            ensure!(hitscan.cooling > 0.0, "Cooling must be positive");
+        } else {
+            ensure!(
+                self.muzzle_velocity > 0.0,
+                "Muzzle velocity must be positive"
+            );
+        }
+
+        let projectile = self
+            .projectile
+            .as_ref()
+            .map(|caliber| {
+                return ammunition
+                    .get(caliber)
+                    .cloned()
+                    .with_context(|| format!("Unknown projectile {}", caliber));
+            })
+            .transpose()?;
+
+        return Ok(WeaponConfig {
+            id: self.id,
+            name: self.name,
+            mass: self.mass,
+            level: self.level,
+            muzzle_velocity: self.muzzle_velocity,
+            deviation: self.deviation,
+            fire_rate: self.fire_rate,
+            fire_modes: self.fire_modes,
+            burst_size: self.burst_size,
+            projectile,
+            hitscan: self.hitscan,
+            melee: self.melee,
+            ammo_capacity: self.ammo_capacity,
+            reloading_time: self.reloading_time,
+            partial_reloading: self.partial_reloading,
+            aim_zoom: self.aim_zoom,
+            attachments: self.attachments.into_iter().collect(),
+            grip: self.grip,
+            image_offset: self.image_offset,
+        });
+    }
+}
+
+fn validate_projectile(projectile: &ProjectileConfig) -> Result<()> {
+    ensure!(!projectile.caliber.is_empty(), "Caliber is empty");
+    ensure!(projectile.fragments > 0, "Fragments must be positive");
+    ensure!(projectile.mass > 0.0, "Mass must be positive");
+    ensure!(projectile.size >= 0.0, "Size must not be negative");
+    return Ok(());
+}
+
+pub fn load_weapons(
+    ammunition_path: &str,
+    weapons_path: &str,
+) -> Result<(Registry<ProjectileConfig>, Registry<WeaponConfig>)> {
+    let ammunition = load_data::<AmmunitionData>(ammunition_path)?.into_registry()?;
+    let weapons = load_data::<WeaponsData>(weapons_path)?.into_registry(&ammunition)?;
+    return Ok((ammunition, weapons));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{parse_data_str, AMMUNITION_PATH, WEAPONS_PATH};
+
+    #[test]
+    fn test_load_bundled_weapons() {
+        let (ammunition, weapons) =
+            load_weapons(AMMUNITION_PATH, WEAPONS_PATH).expect("Failed to load weapons");
+        assert!(!ammunition.is_empty());
+        assert!(!weapons.is_empty());
+
+        for weapon in weapons.as_slice() {
+            if let Some(projectile) = &weapon.projectile {
+                assert!(ammunition.get(&projectile.caliber).is_some());
+            }
+        }
+
+        let laser = weapons.get("laser_gun").expect("No laser gun");
+        assert!(laser.projectile.is_none());
+        assert!(laser.hitscan.is_some());
+
+        let axe = weapons.get("axe").expect("No axe");
+        assert!(axe.projectile.is_none());
+        assert!(axe.melee.is_some());
+
+        let ak = weapons.get("ak_74m").expect("No AK-74M");
+        assert!(ak.attachments.contains(WeaponAttachment::Suppressor));
+    }
+
+    #[test]
+    fn test_unknown_projectile() {
+        let ammunition = parse_data_str::<AmmunitionData>(
+            r#"
+            [[ammunition]]
+            caliber = "9x18"
+            fragments = 1
+            mass = 0.0061
+            size = 0.7
+            "#,
+        )
+        .expect("Failed to parse ammunition")
+        .into_registry()
+        .expect("Failed to register ammunition");
+
+        let weapons = parse_data_str::<WeaponsData>(
+            r#"
+            [[weapons]]
+            id = "pm"
+            name = "PM"
+            mass = 0.73
+            level = 1
+            muzzle_velocity = 315.0
+            deviation = 0.03
+            fire_rate = 400.0
+            fire_modes = ["semi"]
+            projectile = "9x19"
+            ammo_capacity = 8
+            reloading_time = 3.0
+            grip = "one_hand"
+            image_offset = 2.0
+            "#,
+        )
+        .expect("Failed to parse weapons");
+
+        assert!(weapons.into_registry(&ammunition).is_err());
+    }
+
+    #[test]
+    fn test_burst_without_size() {
+        let weapons = parse_data_str::<WeaponsData>(
+            r#"
+            [[weapons]]
+            id = "an_94"
+            name = "AN-94"
+            mass = 3.85
+            level = 4
+            muzzle_velocity = 900.0
+            deviation = 0.01
+            fire_rate = 600.0
+            fire_modes = ["burst", "semi"]
+            projectile = "5.45x39"
+            ammo_capacity = 30
+            reloading_time = 3.0
+            grip = "two_hands_with_butt"
+            image_offset = 2.0
+            "#,
+        )
+        .expect("Failed to parse weapons");
+
+        let (ammunition, _) =
+            load_weapons(AMMUNITION_PATH, WEAPONS_PATH).expect("Failed to load weapons");
+
+        assert!(weapons.into_registry(&ammunition).is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use crate::component::ActorKind;
+use crate::{component::ActorKind, model::Attack};
 use bevy::{math::Vec2, prelude::Event};
 
 #[derive(Event)]
@@ -6,4 +6,6 @@ pub struct ActorDeathEvent {
     pub kind: ActorKind,
     pub position: Vec2,
     pub is_player: bool,
+    /// The last attack on the actor, which made the killing blow or left it bleeding
+    pub attack: Option<Attack>,
 }
@@ -0,0 +1,11 @@
+use crate::model::{Attack, HitZone};
+use bevy::prelude::{Entity, Event};
+
+#[derive(Event)]
+pub struct ActorHitEvent {
+    pub entity: Entity,
+    pub attack: Attack,
+    pub zone: HitZone,
+    pub damage: f32,
+    pub is_killing_blow: bool,
+}
@@ -1,3 +1,4 @@
 mod actor_death;
+mod actor_hit;
 
-pub use self::actor_death::*;
+pub use self::{actor_death::*, actor_hit::*};
@@ -11,13 +11,15 @@ mod system;
 mod util;
 
 use crate::{
+    component::{ActorConfig, ArmorConfig, ExplosiveConfig, ProjectileConfig, WeaponConfig},
     data::{APP_TITLE, CONFIG_PATH},
-    event::ActorDeathEvent,
+    event::{ActorDeathEvent, ActorHitEvent},
     material::{BloodMaterial, LaserMaterial, ProjectileMaterial, StatusBarMaterial},
     model::AppState,
     plugin::DebugPlugin,
     resource::{
-        AssetStorage, AudioStorage, AudioTracker, Cache, Config, GameMode, HitResource, Scenario,
+        AssetStorage, AudioStorage, AudioTracker, Cache, Config, Factions, GameMode, HitResource,
+        Navigation, Noises, Registry, Scenario,
     },
     scenario::{BenchScenario, EmptyScenario, WavesScenario},
     util::ext::AppExt,
@@ -84,7 +86,7 @@ fn main() {
             GameMode::Waves => {
                 scenario = Some(Scenario::new(WavesScenario::new()));
             }
-            GameMode::LaserSight => {}
+            GameMode::LaserSight => {} // weapons get sights in WeaponSet
         }
     }
 
@@ -97,11 +99,20 @@ fn main() {
         .add_plugins(Material2dPlugin::<ProjectileMaterial>::default())
         .add_state::<AppState>()
         .add_event::<ActorDeathEvent>()
+        .add_event::<ActorHitEvent>()
         .insert_resource(AssetStorage::default())
         .insert_resource(AudioStorage::default())
         .insert_resource(AudioTracker::new(config.audio.sources))
         .insert_resource(HitResource::default())
+        .insert_resource(Noises::default())
+        .insert_resource(Navigation::default())
         .insert_resource(Cache::default())
+        .insert_resource(Registry::<ProjectileConfig>::default())
+        .insert_resource(Registry::<WeaponConfig>::default())
+        .insert_resource(Registry::<ExplosiveConfig>::default())
+        .insert_resource(Registry::<ArmorConfig>::default())
+        .insert_resource(Registry::<ActorConfig>::default())
+        .insert_resource(Factions::default())
         .insert_resource(config)
         .insert_resource(GizmoConfig {
             line_width: 5.0,
@@ -116,20 +127,33 @@ fn main() {
             use crate::system::{bot, game::*};
             s.add(input);
             s.add(health);
+            s.add(
+                status_effects
+                    .after(player)
+                    .after(bot::operate)
+                    .before(actor)
+                    .before(health),
+            );
             s.add(player);
             s.add(actor.after(player));
             s.add(inertia.after(actor));
             s.add(collision_find.pipe(collision_resolve).after(inertia));
-            s.add(weapon.after(collision_resolve));
+            s.add(weapon_inventory.after(player));
+            s.add(consumable_inventory.after(player).before(actor));
+            s.add(weapon.after(collision_resolve).after(weapon_inventory));
             s.add(melee.after(collision_resolve));
             s.add(projectile.after(collision_resolve));
-            s.add(hit().after(melee).after(projectile));
+            s.add(grenade.after(collision_resolve).after(weapon_inventory));
+            s.add(explosive.after(collision_resolve));
+            s.add(hit().after(melee).after(projectile).after(explosive));
             s.add(bonus_image);
             s.add(bonus_label);
             s.add(bonus.after(collision_resolve));
+            s.add(laser_sight.after(collision_resolve));
             s.add(camera.after(collision_resolve));
             s.add(status_bar);
             s.add(blood);
+            s.add(beam);
             s.add(breath);
             s.add(footsteps);
             s.add(heartbeat());
@@ -18,6 +18,10 @@ pub struct StatusBarMaterial {
     pub ammo_alpha: f32,
     #[uniform(0)]
     pub stamina: f32,
+    #[uniform(0)]
+    pub armor: f32,
+    #[uniform(0)]
+    pub armor_alpha: f32,
     #[texture(1)]
     #[sampler(2)]
     pub image: Handle<Image>,
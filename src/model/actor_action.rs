@@ -5,6 +5,12 @@ pub enum ActorAction {
     Sprint,
     Attack,
     Reload,
+    Throw,
+    Aim,
+    /// Melee strike, with the weapon stock for firearms
+    Bash,
+    /// Slow and quiet movement
+    Crouch,
 }
 
 pub type ActorActions = EnumSet<ActorAction>;
@@ -15,6 +21,10 @@ pub trait ActorActionsExt {
     fn is_sprinting(self) -> bool;
     fn is_attacking(self) -> bool;
     fn is_reloading(self) -> bool;
+    fn is_throwing(self) -> bool;
+    fn is_aiming(self) -> bool;
+    fn is_bashing(self) -> bool;
+    fn is_crouching(self) -> bool;
 }
 
 impl ActorActionsExt for ActorActions {
@@ -27,7 +37,10 @@ impl ActorActionsExt for ActorActions {
     }
 
     fn is_sprinting(self) -> bool {
-        return self.contains(ActorAction::Sprint);
+        // can't sprint while aiming or crouching
+        return self.contains(ActorAction::Sprint)
+            && !self.contains(ActorAction::Aim)
+            && !self.contains(ActorAction::Crouch);
     }
 
     fn is_attacking(self) -> bool {
@@ -37,4 +50,20 @@ impl ActorActionsExt for ActorActions {
     fn is_reloading(self) -> bool {
         return self.contains(ActorAction::Reload);
     }
+
+    fn is_throwing(self) -> bool {
+        return self.contains(ActorAction::Throw);
+    }
+
+    fn is_aiming(self) -> bool {
+        return self.contains(ActorAction::Aim);
+    }
+
+    fn is_bashing(self) -> bool {
+        return self.contains(ActorAction::Bash);
+    }
+
+    fn is_crouching(self) -> bool {
+        return self.contains(ActorAction::Crouch);
+    }
 }
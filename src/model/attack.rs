@@ -0,0 +1,45 @@
+use crate::component::{ExplosiveConfig, ProjectileConfig, WeaponConfig};
+use bevy::ecs::entity::Entity;
+use std::sync::Arc;
+
+/// Who made a hit and with what
+#[derive(Clone)]
+pub struct Attack {
+    pub attacker: Option<Entity>,
+    pub weapon: Option<Arc<WeaponConfig>>,
+    pub source: HitSource,
+}
+
+#[derive(Clone)]
+pub enum HitSource {
+    Projectile(Arc<ProjectileConfig>),
+    Hitscan,
+    Melee,
+    Explosion(Arc<ExplosiveConfig>),
+}
+
+impl Attack {
+    /// Name of what made the hit, for messages
+    pub fn get_name(&self) -> &str {
+        if let Some(weapon) = &self.weapon {
+            return &weapon.name;
+        }
+
+        return match &self.source {
+            HitSource::Projectile(projectile) => &projectile.caliber,
+            HitSource::Hitscan => "laser",
+            HitSource::Melee => "melee",
+            HitSource::Explosion(explosive) => &explosive.name,
+        };
+    }
+}
+
+impl HitSource {
+    pub fn get_projectile(&self) -> Option<&ProjectileConfig> {
+        if let Self::Projectile(projectile) = self {
+            return Some(projectile);
+        } else {
+            return None;
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Faction {
+    Loners,
+    Bandits,
+    Military,
+    Zombies,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Relationship {
+    Hostile,
+    Neutral,
+    Allied,
+}
+
+impl Faction {
+    pub fn name(self) -> &'static str {
+        return match self {
+            Self::Loners => "Loners",
+            Self::Bandits => "Bandits",
+            Self::Military => "Military",
+            Self::Zombies => "Zombies",
+        };
+    }
+}
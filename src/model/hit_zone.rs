@@ -0,0 +1,89 @@
+use crate::{model::TransformLite, util::ext::Vec2Ext};
+use bevy::math::Vec2;
+use enumset::{EnumSet, EnumSetType};
+use serde::Deserialize;
+
+/// Body part taking a hit, seen from above
+#[derive(Debug, Deserialize, EnumSetType)]
+#[serde(rename_all = "snake_case")]
+pub enum HitZone {
+    Head,
+    Torso,
+    Arm,
+    Leg,
+}
+
+pub type HitZones = EnumSet<HitZone>;
+
+impl HitZone {
+    /// Head takes the middle of the body, relative to its radius
+    const HEAD_RADIUS: f32 = 0.35;
+    /// Arms stick out to the sides, legs stride to the front and back
+    const LIMB_OFFSET: f32 = 0.6;
+
+    /// Finds the zone by the contact point and the target's facing.
+    /// For projectiles the contact is the closest point of the trajectory to the target
+    pub fn find(contact: Vec2, target: &TransformLite, radius: f32) -> Self {
+        let local = (contact - target.translation).rotate_by(-target.direction) / radius;
+
+        if local.length() < Self::HEAD_RADIUS {
+            return Self::Head;
+        } else if local.y.abs() > Self::LIMB_OFFSET {
+            return Self::Arm;
+        } else if local.x.abs() > Self::LIMB_OFFSET {
+            return Self::Leg;
+        } else {
+            return Self::Torso;
+        }
+    }
+
+    pub fn damage_factor(self) -> f32 {
+        return match self {
+            Self::Head => 2.0,
+            Self::Torso => 1.0,
+            Self::Arm => 0.6,
+            Self::Leg => 0.7,
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::FRAC_PI_2;
+
+    #[test]
+    fn test_find() {
+        let target = TransformLite::new(10.0, 0.0, 0.0);
+        assert_eq!(
+            HitZone::find(Vec2::new(10.1, 0.0), &target, 1.0),
+            HitZone::Head
+        );
+        assert_eq!(
+            HitZone::find(Vec2::new(10.0, 0.5), &target, 1.0),
+            HitZone::Torso
+        );
+        assert_eq!(
+            HitZone::find(Vec2::new(10.0, -0.8), &target, 1.0),
+            HitZone::Arm
+        );
+        assert_eq!(
+            HitZone::find(Vec2::new(10.8, 0.0), &target, 1.0),
+            HitZone::Leg
+        );
+    }
+
+    #[test]
+    fn test_find_rotated() {
+        // facing up, so arms are to the left and right
+        let target = TransformLite::new(0.0, 0.0, FRAC_PI_2);
+        assert_eq!(
+            HitZone::find(Vec2::new(0.8, 0.0), &target, 1.0),
+            HitZone::Arm
+        );
+        assert_eq!(
+            HitZone::find(Vec2::new(0.0, -0.8), &target, 1.0),
+            HitZone::Leg
+        );
+    }
+}
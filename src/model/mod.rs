@@ -1,7 +1,16 @@
 mod actor_action;
 mod app_state;
+mod attack;
 mod audio_play;
+mod faction;
 pub mod geometry;
+mod hit_zone;
+mod nav_grid;
+mod nav_path;
+mod noise;
 mod transform;
 
-pub use self::{actor_action::*, app_state::*, audio_play::*, transform::*};
+pub use self::{
+    actor_action::*, app_state::*, attack::*, audio_play::*, faction::*, hit_zone::*, nav_grid::*,
+    nav_path::*, noise::*, transform::*,
+};
@@ -0,0 +1,419 @@
+use crate::component::Obstacle;
+use bevy::math::Vec2;
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+const COST_STRAIGHT: u32 = 10;
+const COST_DIAGONAL: u32 = 14;
+
+/// Square grid of walkable cells centered at the world origin
+pub struct NavGrid {
+    origin: Vec2,
+    size: usize,
+    blocked: Vec<bool>,
+    /// Obstacles with their centers
+    obstacles: Vec<(Obstacle, Vec2)>,
+    /// Obstacles overlapping each cell. Those beyond the grid are kept in its edge cells
+    buckets: Vec<Vec<usize>>,
+}
+
+impl NavGrid {
+    pub const CELL_SIZE: f32 = 0.5;
+    /// Room kept around obstacles, about an actor radius
+    const CLEARANCE: f32 = 0.25;
+    /// Cells to expand before giving up on a path
+    const EXPANSIONS_MAX: usize = 8192;
+    /// How far to look for a free cell when the goal is blocked
+    const GOAL_FREE_RADIUS: usize = 2;
+
+    /// Creates an open grid covering a square with the given side
+    pub fn new(extent: f32) -> Self {
+        let size = (extent / Self::CELL_SIZE).ceil() as usize;
+
+        return Self {
+            origin: Vec2::splat(size as f32 * Self::CELL_SIZE / -2.0),
+            size,
+            blocked: vec![false; size * size],
+            obstacles: Vec::new(),
+            buckets: vec![Vec::new(); size * size],
+        };
+    }
+
+    /// Marks cells the obstacle leaves no room to walk through
+    pub fn block(&mut self, obstacle: &Obstacle, center: Vec2) {
+        let half_size = obstacle.get_half_size() + Self::CLEARANCE;
+        let (x_min, y_min) = self.to_coordinates_clamped(center - half_size);
+        let (x_max, y_max) = self.to_coordinates_clamped(center + half_size);
+        let index = self.obstacles.len();
+        self.obstacles.push((*obstacle, center));
+
+        for y in y_min..=y_max {
+            for x in x_min..=x_max {
+                let cell = y * self.size + x;
+                self.buckets[cell].push(index);
+
+                if obstacle
+                    .resolve(center, self.get_center(cell), Self::CLEARANCE)
+                    .is_some()
+                {
+                    self.blocked[cell] = true;
+                }
+            }
+        }
+    }
+
+    /// Cell at the position, None if it's outside the grid
+    pub fn to_cell(&self, position: Vec2) -> Option<usize> {
+        let local = (position - self.origin) / Self::CELL_SIZE;
+
+        if local.x < 0.0 || local.y < 0.0 {
+            return None;
+        }
+
+        let (x, y) = (local.x as usize, local.y as usize);
+
+        if x < self.size && y < self.size {
+            return Some(y * self.size + x);
+        } else {
+            return None;
+        }
+    }
+
+    /// Cell at the position, or the closest one on the edge if it's outside the grid
+    pub fn to_cell_clamped(&self, position: Vec2) -> usize {
+        let (x, y) = self.to_coordinates_clamped(position);
+        return y * self.size + x;
+    }
+
+    pub fn get_center(&self, cell: usize) -> Vec2 {
+        let x = (cell % self.size) as f32 + 0.5;
+        let y = (cell / self.size) as f32 + 0.5;
+        return self.origin + Vec2::new(x, y) * Self::CELL_SIZE;
+    }
+
+    /// Whether a straight walk between the positions stays off blocked cells.
+    /// The starting cell is ignored, so actors pressed against an obstacle can still leave it
+    pub fn is_clear(&self, from: Vec2, to: Vec2) -> bool {
+        let from_cell = self.to_cell(from);
+        let steps = (from.distance(to) / Self::CELL_SIZE * 2.0).ceil() as usize;
+
+        for i in 1..=steps {
+            let sample = from.lerp(to, i as f32 / steps as f32);
+
+            if let Some(cell) = self.to_cell(sample) {
+                if Some(cell) != from_cell && self.blocked[cell] {
+                    return false;
+                }
+            }
+        }
+
+        return true;
+    }
+
+    /// Whether no obstacle blocks the line of sight between the positions
+    pub fn is_visible(&self, from: Vec2, to: Vec2) -> bool {
+        let segment = (from, to);
+        let mut checked = Vec::new();
+
+        for cell in self.get_cells_on_line(from, to) {
+            for &index in &self.buckets[cell] {
+                if checked.contains(&index) {
+                    continue;
+                }
+
+                checked.push(index);
+                let (obstacle, center) = &self.obstacles[index];
+
+                if obstacle.intersect(*center, &segment).is_some() {
+                    return false;
+                }
+            }
+        }
+
+        return true;
+    }
+
+    /// Finds a path between the cells with A*. Returns the waypoints after the start, shortened
+    /// to turning points only. The last one is the goal, or a free cell near it if it's blocked
+    pub fn find_path(&self, start: usize, goal: usize) -> Option<Vec<Vec2>> {
+        let goal = self.find_free(goal)?;
+        let mut costs = vec![u32::MAX; self.blocked.len()];
+        let mut parents = vec![usize::MAX; self.blocked.len()];
+        let mut closed = vec![false; self.blocked.len()];
+        let mut open = BinaryHeap::new();
+        let mut expansions = 0;
+
+        costs[start] = 0;
+        open.push(Reverse((self.estimate(start, goal), start)));
+
+        while let Some(Reverse((_, cell))) = open.pop() {
+            if cell == goal {
+                return Some(self.smooth(&Self::trace(&parents, start, goal)));
+            }
+
+            if closed[cell] {
+                continue;
+            }
+
+            closed[cell] = true;
+            expansions += 1;
+
+            if expansions > Self::EXPANSIONS_MAX {
+                return None;
+            }
+
+            for (neighbor, step) in self.get_neighbors(cell) {
+                let cost = costs[cell] + step;
+
+                if cost < costs[neighbor] {
+                    costs[neighbor] = cost;
+                    parents[neighbor] = cell;
+                    open.push(Reverse((cost + self.estimate(neighbor, goal), neighbor)));
+                }
+            }
+        }
+
+        return None;
+    }
+
+    fn to_local_clamped(&self, position: Vec2) -> Vec2 {
+        let local = (position - self.origin) / Self::CELL_SIZE;
+        return local.clamp(Vec2::ZERO, Vec2::splat(self.size as f32));
+    }
+
+    fn to_coordinates_clamped(&self, position: Vec2) -> (usize, usize) {
+        let local = (position - self.origin) / Self::CELL_SIZE;
+        let max = self.size as f32 - 1.0;
+        return (
+            local.x.clamp(0.0, max) as usize,
+            local.y.clamp(0.0, max) as usize,
+        );
+    }
+
+    /// Cells the line crosses, in order. Parts beyond the grid are clamped to its edge
+    fn get_cells_on_line(&self, from: Vec2, to: Vec2) -> Vec<usize> {
+        let start = self.to_local_clamped(from);
+        let delta = self.to_local_clamped(to) - start;
+        let (mut x, mut y) = self.to_coordinates_clamped(from);
+        let (x_end, y_end) = self.to_coordinates_clamped(to);
+        let (mut next_x, step_x) = Self::get_crossing(start.x, x, delta.x);
+        let (mut next_y, step_y) = Self::get_crossing(start.y, y, delta.y);
+        let mut cells = vec![y * self.size + x];
+
+        for _ in 0..x.abs_diff(x_end) + y.abs_diff(y_end) {
+            if y == y_end || (x != x_end && next_x < next_y) {
+                if x < x_end {
+                    x += 1;
+                } else {
+                    x -= 1;
+                }
+
+                next_x += step_x;
+            } else {
+                if y < y_end {
+                    y += 1;
+                } else {
+                    y -= 1;
+                }
+
+                next_y += step_y;
+            }
+
+            cells.push(y * self.size + x);
+        }
+
+        return cells;
+    }
+
+    /// Where along the line it first crosses a cell border on the axis, and the distance between
+    /// the crossings, both as fractions of the line
+    fn get_crossing(start: f32, cell: usize, delta: f32) -> (f32, f32) {
+        if delta > 0.0 {
+            return ((cell as f32 + 1.0 - start) / delta, 1.0 / delta);
+        } else if delta < 0.0 {
+            return ((start - cell as f32) / -delta, 1.0 / -delta);
+        } else {
+            return (f32::INFINITY, f32::INFINITY);
+        }
+    }
+
+    fn find_free(&self, cell: usize) -> Option<usize> {
+        if !self.blocked[cell] {
+            return Some(cell);
+        }
+
+        let (x, y) = (cell % self.size, cell / self.size);
+
+        for radius in 1..=Self::GOAL_FREE_RADIUS {
+            let x_range = x.saturating_sub(radius)..=usize::min(x + radius, self.size - 1);
+            let y_range = y.saturating_sub(radius)..=usize::min(y + radius, self.size - 1);
+
+            for ny in y_range {
+                for nx in x_range.clone() {
+                    let neighbor = ny * self.size + nx;
+
+                    if !self.blocked[neighbor] {
+                        return Some(neighbor);
+                    }
+                }
+            }
+        }
+
+        return None;
+    }
+
+    /// Free neighbor cells with step costs. Diagonal steps can't cut obstacle corners
+    fn get_neighbors(&self, cell: usize) -> impl Iterator<Item = (usize, u32)> + '_ {
+        let (x, y) = ((cell % self.size) as isize, (cell / self.size) as isize);
+
+        return [
+            (-1, -1),
+            (0, -1),
+            (1, -1),
+            (-1, 0),
+            (1, 0),
+            (-1, 1),
+            (0, 1),
+            (1, 1),
+        ]
+        .into_iter()
+        .filter_map(move |(dx, dy)| {
+            let neighbor = self.get_free(x + dx, y + dy)?;
+
+            if dx != 0 && dy != 0 {
+                self.get_free(x + dx, y)?;
+                self.get_free(x, y + dy)?;
+                return Some((neighbor, COST_DIAGONAL));
+            } else {
+                return Some((neighbor, COST_STRAIGHT));
+            }
+        });
+    }
+
+    fn get_free(&self, x: isize, y: isize) -> Option<usize> {
+        let x = usize::try_from(x).ok().filter(|x| *x < self.size)?;
+        let y = usize::try_from(y).ok().filter(|y| *y < self.size)?;
+        let cell = y * self.size + x;
+        return (!self.blocked[cell]).then_some(cell);
+    }
+
+    /// Octile distance, exact on an open grid
+    fn estimate(&self, from: usize, to: usize) -> u32 {
+        let dx = (from % self.size).abs_diff(to % self.size) as u32;
+        let dy = (from / self.size).abs_diff(to / self.size) as u32;
+        return COST_STRAIGHT * u32::max(dx, dy)
+            + (COST_DIAGONAL - COST_STRAIGHT) * u32::min(dx, dy);
+    }
+
+    /// Cells from the start to the goal, both included
+    fn trace(parents: &[usize], start: usize, goal: usize) -> Vec<usize> {
+        let mut cells = vec![goal];
+        let mut cell = goal;
+
+        while cell != start {
+            cell = parents[cell];
+            cells.push(cell);
+        }
+
+        cells.reverse();
+        return cells;
+    }
+
+    /// Drops waypoints which can be skipped by walking straight
+    fn smooth(&self, cells: &[usize]) -> Vec<Vec2> {
+        let mut waypoints = Vec::new();
+        let mut anchor = self.get_center(cells[0]);
+        let mut i = 1;
+
+        while i < cells.len() {
+            while i + 1 < cells.len() && self.is_clear(anchor, self.get_center(cells[i + 1])) {
+                i += 1;
+            }
+
+            anchor = self.get_center(cells[i]);
+            waypoints.push(anchor);
+            i += 1;
+        }
+
+        return waypoints;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::ext::Vec2Ext;
+
+    /// A wall across the middle of a 10x10m grid, with a gap at the top
+    fn grid_with_wall() -> NavGrid {
+        let mut grid = NavGrid::new(10.0);
+
+        grid.block(
+            &Obstacle::Box {
+                half_size: Vec2::new(0.25, 3.5),
+            },
+            Vec2::new(0.0, -1.5),
+        );
+
+        return grid;
+    }
+
+    fn find_path(grid: &NavGrid, from: Vec2, to: Vec2) -> Option<Vec<Vec2>> {
+        return grid.find_path(grid.to_cell(from)?, grid.to_cell(to)?);
+    }
+
+    #[test]
+    fn test_open_path() {
+        let grid = NavGrid::new(10.0);
+        let to = Vec2::new(4.0, 4.0);
+        let path = find_path(&grid, Vec2::new(-4.0, -4.0), to).expect("No path");
+
+        assert_eq!(path.len(), 1);
+        assert!(path[0].is_close(to, NavGrid::CELL_SIZE));
+    }
+
+    #[test]
+    fn test_path_around_wall() {
+        let grid = grid_with_wall();
+        let from = Vec2::new(-3.0, -3.0);
+        let to = Vec2::new(3.0, -3.0);
+        assert!(!grid.is_clear(from, to));
+
+        let path = find_path(&grid, from, to).expect("No path");
+        let mut position = grid.get_center(grid.to_cell(from).expect("Outside the grid"));
+
+        for waypoint in &path {
+            assert!(grid.is_clear(position, *waypoint));
+            position = *waypoint;
+        }
+
+        assert!(path.iter().any(|w| w.y > 2.0));
+        assert!(position.is_close(to, NavGrid::CELL_SIZE));
+    }
+
+    #[test]
+    fn test_visibility() {
+        let grid = grid_with_wall();
+        assert!(!grid.is_visible(Vec2::new(-3.0, -3.0), Vec2::new(3.0, -1.0)));
+        assert!(grid.is_visible(Vec2::new(-3.0, 3.0), Vec2::new(3.0, 3.0)));
+
+        // right next to the wall, where cells are blocked for walking
+        assert!(grid.is_visible(Vec2::new(-0.3, -4.0), Vec2::new(-0.3, 1.0)));
+
+        // the line crosses the wall corner diagonally
+        assert!(!grid.is_visible(Vec2::new(-1.0, 1.5), Vec2::new(1.0, 2.5)));
+    }
+
+    #[test]
+    fn test_unreachable() {
+        let mut grid = grid_with_wall();
+
+        grid.block(
+            &Obstacle::Box {
+                half_size: Vec2::new(0.25, 1.5),
+            },
+            Vec2::new(0.0, 3.5),
+        );
+
+        assert!(find_path(&grid, Vec2::new(-3.0, 0.0), Vec2::new(3.0, 0.0)).is_none());
+    }
+}
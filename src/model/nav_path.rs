@@ -0,0 +1,74 @@
+use crate::util::ext::Vec2Ext;
+use bevy::math::Vec2;
+use std::sync::Arc;
+
+/// Route towards a goal around obstacles
+#[derive(Default)]
+pub struct NavPath {
+    goal: Option<Vec2>,
+    /// None if the goal is unreachable. Empty if it can be reached by walking straight
+    waypoints: Option<Arc<[Vec2]>>,
+    next: usize,
+}
+
+impl NavPath {
+    const WAYPOINT_REACH: f32 = 0.3;
+
+    pub fn new(goal: Vec2, waypoints: Option<Arc<[Vec2]>>) -> Self {
+        return Self {
+            goal: Some(goal),
+            waypoints,
+            next: 0,
+        };
+    }
+
+    pub fn get_goal(&self) -> Option<Vec2> {
+        return self.goal;
+    }
+
+    /// Where to head from the position, skipping reached waypoints. Once they're passed, it's
+    /// the current goal, which may have moved a bit since the search. None if there is no way
+    pub fn next_waypoint(&mut self, position: Vec2, goal: Vec2) -> Option<Vec2> {
+        let waypoints = self.waypoints.as_ref()?;
+
+        while let Some(waypoint) = waypoints.get(self.next) {
+            if waypoint.is_far(position, Self::WAYPOINT_REACH) {
+                return Some(*waypoint);
+            }
+
+            self.next += 1;
+        }
+
+        return Some(goal);
+    }
+
+    pub fn iter_waypoints(&self) -> impl Iterator<Item = &Vec2> {
+        return self.waypoints.iter().flat_map(|w| w.iter()).skip(self.next);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_waypoint() {
+        let goal = Vec2::new(2.0, 0.0);
+        let goal_moved = Vec2::new(2.5, 0.0);
+        let waypoints = Arc::from(vec![Vec2::new(1.0, 1.0), goal]);
+        let mut path = NavPath::new(goal, Some(waypoints));
+
+        assert_eq!(
+            path.next_waypoint(Vec2::ZERO, goal_moved),
+            Some(Vec2::new(1.0, 1.0)),
+        );
+        assert_eq!(
+            path.next_waypoint(Vec2::new(1.0, 0.9), goal_moved),
+            Some(goal)
+        );
+        assert_eq!(path.next_waypoint(goal, goal_moved), Some(goal_moved));
+
+        let mut path = NavPath::new(goal, None);
+        assert_eq!(path.next_waypoint(Vec2::ZERO, goal), None);
+    }
+}
@@ -0,0 +1,12 @@
+use bevy::{math::Vec2, prelude::Entity};
+use std::time::Duration;
+
+/// A sound bots can hear
+pub struct Noise {
+    pub position: Vec2,
+    /// Distance the noise can be heard from, meters
+    pub loudness: f32,
+    /// Who made the noise, if anyone
+    pub emitter: Option<Entity>,
+    pub time: Duration,
+}
@@ -1,9 +1,10 @@
 use crate::{
     command::{ActorBotSet, ActorSet, BonusSpawn, WeaponSet},
-    component::{ActorConfig, ActorKind, Player, WeaponConfig},
+    component::{ActorConfig, ActorKind, Player},
     data::FONT_PATH,
+    event::ActorHitEvent,
     model::{AppState, TransformLite},
-    resource::AudioTracker,
+    resource::{AudioTracker, Registry},
     util::{
         ext::{AppExt, Vec2Ext},
         Timer, GIZMOS,
@@ -16,15 +17,15 @@ use bevy::{
     gizmos::gizmos::Gizmos,
     input::Input,
     prelude::{
-        AssetServer, Color, Commands, Component, IntoSystemConfigs, KeyCode, Query, Res, Startup,
-        TextBundle, Update, Vec2, With,
+        AssetServer, Color, Commands, Component, EventReader, IntoSystemConfigs, KeyCode, Query,
+        Res, Startup, TextBundle, Update, Vec2, With,
     },
     text::{Text, TextSection, TextStyle},
     time::Time,
     transform::components::Transform,
 };
 use rand::Rng;
-use std::time::Duration;
+use std::{sync::Arc, time::Duration};
 
 const INTERVAL: Duration = Duration::from_millis(500);
 
@@ -45,7 +46,8 @@ impl Plugin for DebugPlugin {
             .add_systems(Startup, startup)
             .add_systems(Update, update_diagnostics())
             .add_systems(Update, render_gizmos_static)
-            .add_state_system(AppState::Game, update_input);
+            .add_state_system(AppState::Game, update_input)
+            .add_state_system(AppState::Game, log_hits);
     }
 }
 
@@ -69,6 +71,7 @@ fn startup(mut commands: Commands, asset_server: Res<AssetServer>) {
                 \nSpawn weapon: [G]\
                 \nSpawn human : [H] group: [+SHIFT]\
                 \nSpawn zombie: [J] group: [+SHIFT]\
+                \nSpawn next archetype: [K] group: [+SHIFT]\
                 ",
                 style,
             ),
@@ -105,21 +108,48 @@ fn update_diagnostics() -> SystemConfigs {
     });
 }
 
+fn log_hits(mut hits: EventReader<ActorHitEvent>) {
+    for hit in hits.read() {
+        log::debug!(
+            "{:?} hit {:?} in {:?} with {} for {:.2}{}",
+            hit.attack.attacker,
+            hit.entity,
+            hit.zone,
+            hit.attack.get_name(),
+            hit.damage,
+            if hit.is_killing_blow {
+                ", killing blow"
+            } else {
+                ""
+            },
+        );
+    }
+}
+
 fn render_gizmos_static(mut gizmos: Gizmos) {
     GIZMOS.render(&mut gizmos);
 }
 
 fn update_input(
+    mut archetype_index: Local<usize>,
     players: Query<&Transform, With<Player>>,
+    actors: Res<Registry<ActorConfig>>,
     keyboard: Res<Input<KeyCode>>,
     mut commands: Commands,
 ) {
     let spawn = if keyboard.just_pressed(KeyCode::G) {
         Spawn::Bonus
     } else if keyboard.just_pressed(KeyCode::H) {
-        Spawn::Human
+        Spawn::Actor(actors.get("human"))
     } else if keyboard.just_pressed(KeyCode::J) {
-        Spawn::Zombie
+        Spawn::Actor(actors.get("zombie"))
+    } else if keyboard.just_pressed(KeyCode::K) {
+        let actor = actors
+            .as_slice()
+            .get(*archetype_index % usize::max(actors.len(), 1));
+
+        *archetype_index += 1;
+        Spawn::Actor(actor)
     } else {
         return;
     };
@@ -142,11 +172,11 @@ fn update_input(
         Spawn::Bonus => {
             commands.add(BonusSpawn::new(position.translation, 6)); // TODO: don't hardcode level
         }
-        Spawn::Human => {
-            spawn_actors(&mut commands, position, &ActorConfig::HUMAN, group);
+        Spawn::Actor(Some(config)) => {
+            spawn_actors(&mut commands, position, config, group);
         }
-        Spawn::Zombie => {
-            spawn_actors(&mut commands, position, &ActorConfig::ZOMBIE, group);
+        Spawn::Actor(None) => {
+            log::warn!("Can't spawn actor. Archetype not found");
         }
     }
 }
@@ -154,7 +184,7 @@ fn update_input(
 fn spawn_actors(
     commands: &mut Commands,
     transform: TransformLite,
-    config: &'static ActorConfig,
+    config: &ActorConfig,
     group: u8,
 ) {
     for _ in 0..group {
@@ -162,7 +192,7 @@ fn spawn_actors(
 
         commands.add(ActorSet {
             entity,
-            config,
+            actor: config.id.clone().into(),
             skill: 1.0,
             transform,
         });
@@ -174,16 +204,16 @@ fn spawn_actors(
         let weapon = match config.kind {
             ActorKind::Human => {
                 if weapon_chance < HUMAN_RIFLE_CHANCE {
-                    Some(&WeaponConfig::AKS_74U)
+                    Some("aks_74u")
                 } else {
-                    Some(&WeaponConfig::PM)
+                    Some("pm")
                 }
             }
             ActorKind::Zombie => {
                 if weapon_chance < ZOMBIE_RIFLE_CHANCE {
-                    Some(&WeaponConfig::AKS_74U)
+                    Some("aks_74u")
                 } else if weapon_chance < ZOMBIE_PISTOL_CHANCE {
-                    Some(&WeaponConfig::PM)
+                    Some("pm")
                 } else {
                     None
                 }
@@ -193,14 +223,13 @@ fn spawn_actors(
         if let Some(weapon) = weapon {
             commands.add(WeaponSet {
                 entity,
-                weapon: Some(weapon),
+                weapon: Some(weapon.into()),
             });
         }
     }
 }
 
-enum Spawn {
+enum Spawn<'a> {
     Bonus,
-    Human,
-    Zombie,
+    Actor(Option<&'a Arc<ActorConfig>>),
 }
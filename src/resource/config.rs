@@ -1,3 +1,5 @@
+use super::Factions;
+use crate::component::Actor;
 use anyhow::{Context, Result};
 use bevy::{
     ecs::system::Resource,
@@ -18,12 +20,15 @@ pub struct Config {
 #[derive(Debug, Clone, Deserialize)]
 pub struct GameConfig {
     pub modes: Vec<GameMode>,
+    #[serde(default)]
+    pub friendly_fire: FriendlyFire,
 }
 
 impl Default for GameConfig {
     fn default() -> Self {
         return Self {
             modes: vec![GameMode::Waves],
+            friendly_fire: FriendlyFire::default(),
         };
     }
 }
@@ -48,6 +53,37 @@ impl GameMode {
     }
 }
 
+/// Whether allies can hurt each other
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FriendlyFire {
+    /// Attacks pass allies by
+    #[default]
+    Off,
+    /// Allies take a share of the damage
+    Reduced,
+    Full,
+}
+
+impl FriendlyFire {
+    const REDUCED_DAMAGE: f32 = 0.3;
+
+    /// Whether an attack by the attacker should skip the target
+    pub fn spares(self, factions: &Factions, attacker: Option<&Actor>, target: &Actor) -> bool {
+        return self == Self::Off
+            && attacker.map_or(false, |a| factions.is_allied(a.faction, target.faction));
+    }
+
+    /// Damage multiplier for a hit between allies
+    pub fn get_damage_factor(self) -> f32 {
+        return match self {
+            Self::Off => 0.0,
+            Self::Reduced => Self::REDUCED_DAMAGE,
+            Self::Full => 1.0,
+        };
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct DisplayConfig {
     pub fullscreen: bool,
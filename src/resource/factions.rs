@@ -0,0 +1,79 @@
+use crate::model::{Faction, Relationship};
+use bevy::ecs::system::Resource;
+use std::collections::HashMap;
+
+/// Relationships between factions. Members of a faction are always allies,
+/// unknown pairs are neutral
+#[derive(Default, Resource)]
+pub struct Factions {
+    relationships: HashMap<(Faction, Faction), Relationship>,
+}
+
+impl Factions {
+    pub fn get(&self, a: Faction, b: Faction) -> Relationship {
+        if a == b {
+            return Relationship::Allied;
+        }
+
+        return self
+            .relationships
+            .get(&Self::key(a, b))
+            .copied()
+            .unwrap_or(Relationship::Neutral);
+    }
+
+    pub fn set(&mut self, a: Faction, b: Faction, relationship: Relationship) {
+        if a != b {
+            self.relationships.insert(Self::key(a, b), relationship);
+        }
+    }
+
+    /// Turns a neutral faction hostile to the attacker's one. Returns whether it has changed
+    pub fn provoke(&mut self, attacker: Faction, target: Faction) -> bool {
+        if self.get(attacker, target) == Relationship::Neutral {
+            self.set(attacker, target, Relationship::Hostile);
+            return true;
+        } else {
+            return false;
+        }
+    }
+
+    pub fn is_hostile(&self, a: Faction, b: Faction) -> bool {
+        return self.get(a, b) == Relationship::Hostile;
+    }
+
+    pub fn is_allied(&self, a: Faction, b: Faction) -> bool {
+        return self.get(a, b) == Relationship::Allied;
+    }
+
+    fn key(a: Faction, b: Faction) -> (Faction, Faction) {
+        return (Faction::min(a, b), Faction::max(a, b));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_symmetric() {
+        let mut factions = Factions::default();
+        factions.set(Faction::Loners, Faction::Bandits, Relationship::Hostile);
+        assert!(factions.is_hostile(Faction::Bandits, Faction::Loners));
+        assert!(factions.is_allied(Faction::Bandits, Faction::Bandits));
+        assert_eq!(
+            factions.get(Faction::Loners, Faction::Military),
+            Relationship::Neutral,
+        );
+    }
+
+    #[test]
+    fn test_provoke() {
+        let mut factions = Factions::default();
+        factions.set(Faction::Loners, Faction::Zombies, Relationship::Hostile);
+        assert!(factions.provoke(Faction::Loners, Faction::Military));
+        assert!(factions.is_hostile(Faction::Military, Faction::Loners));
+        assert!(!factions.provoke(Faction::Loners, Faction::Zombies));
+        assert!(!factions.provoke(Faction::Loners, Faction::Loners));
+    }
+}
@@ -1,3 +1,4 @@
+use crate::model::{Attack, HitZone};
 use bevy::{
     ecs::system::Resource,
     prelude::{Entity, Vec2},
@@ -10,8 +11,16 @@ pub struct HitResource {
 }
 
 impl HitResource {
-    pub fn add(&mut self, entity: Entity, momentum: Vec2, angle: f32) {
-        self.hits.push(HitTarget::new(entity, momentum, angle));
+    pub fn add(
+        &mut self,
+        entity: Entity,
+        momentum: Vec2,
+        angle: f32,
+        zone: HitZone,
+        attack: Attack,
+    ) {
+        self.hits
+            .push(HitTarget::new(entity, momentum, angle, zone, attack));
     }
 }
 
@@ -20,4 +29,6 @@ pub struct HitTarget {
     pub entity: Entity,
     pub momentum: Vec2,
     pub angle: f32,
+    pub zone: HitZone,
+    pub attack: Attack,
 }
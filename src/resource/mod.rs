@@ -3,9 +3,14 @@ mod audio_storage;
 mod audio_tracker;
 mod cache;
 mod config;
+mod factions;
 mod hit;
+mod navigation;
+mod noises;
+mod registry;
 mod scenario;
 
 pub(crate) use self::{
-    asset_storage::*, audio_storage::*, audio_tracker::*, cache::*, config::*, hit::*, scenario::*,
+    asset_storage::*, audio_storage::*, audio_tracker::*, cache::*, config::*, factions::*, hit::*,
+    navigation::*, noises::*, registry::*, scenario::*,
 };
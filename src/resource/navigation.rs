@@ -0,0 +1,138 @@
+use crate::model::NavGrid;
+use bevy::{ecs::system::Resource, math::Vec2};
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Arc,
+};
+
+/// Path search over the navigation grid. Results are cached, and searches are limited per frame
+#[derive(Default, Resource)]
+pub struct Navigation {
+    grid: Option<NavGrid>,
+    cache: HashMap<(usize, usize), Option<Arc<[Vec2]>>>,
+    /// Cached searches, the oldest first
+    cache_order: VecDeque<(usize, usize)>,
+    budget: usize,
+}
+
+pub enum NavQuery {
+    /// Nothing is in the way
+    Direct,
+    Found(Arc<[Vec2]>),
+    Unreachable,
+    /// Out of searches for this frame, try again on the next one
+    Postponed,
+}
+
+impl Navigation {
+    const SEARCHES_PER_FRAME: usize = 8;
+    const CACHE_SIZE: usize = 4096;
+
+    pub fn set_grid(&mut self, grid: NavGrid) {
+        self.grid = Some(grid);
+        self.cache.clear();
+        self.cache_order.clear();
+    }
+
+    pub fn reset_budget(&mut self) {
+        self.budget = Self::SEARCHES_PER_FRAME;
+    }
+
+    /// Whether static obstacles don't block the line of sight. Nothing blocks it before the grid
+    /// is built
+    pub fn is_visible(&self, from: Vec2, to: Vec2) -> bool {
+        return self.grid.as_ref().map_or(true, |g| g.is_visible(from, to));
+    }
+
+    pub fn find_path(&mut self, from: Vec2, to: Vec2) -> NavQuery {
+        let Some(grid) = self.grid.as_ref() else {
+            return NavQuery::Direct;
+        };
+
+        // actors beyond the grid have nothing to walk around
+        let Some(start) = grid.to_cell(from).filter(|_| !grid.is_clear(from, to)) else {
+            return NavQuery::Direct;
+        };
+
+        // head to the edge of the grid, the rest of the way is open
+        let goal = grid.to_cell_clamped(to);
+
+        let path = if let Some(path) = self.cache.get(&(start, goal)) {
+            path.clone()
+        } else if self.budget == 0 {
+            return NavQuery::Postponed;
+        } else {
+            self.budget -= 1;
+            let path: Option<Arc<[Vec2]>> = grid.find_path(start, goal).map(Arc::from);
+
+            if self.cache.len() >= Self::CACHE_SIZE {
+                if let Some(oldest) = self.cache_order.pop_front() {
+                    self.cache.remove(&oldest);
+                }
+            }
+
+            self.cache.insert((start, goal), path.clone());
+            self.cache_order.push_back((start, goal));
+            path
+        };
+
+        return path.map_or(NavQuery::Unreachable, NavQuery::Found);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::component::Obstacle;
+
+    #[test]
+    fn test_budget() {
+        let mut grid = NavGrid::new(10.0);
+        grid.block(&Obstacle::Circle { radius: 1.0 }, Vec2::ZERO);
+
+        let mut navigation = Navigation::default();
+        navigation.set_grid(grid);
+
+        let from = Vec2::new(-3.0, 0.0);
+        let to = Vec2::new(3.0, 0.0);
+        assert!(matches!(
+            navigation.find_path(from, to),
+            NavQuery::Postponed
+        ));
+
+        navigation.reset_budget();
+        assert!(matches!(navigation.find_path(from, to), NavQuery::Found(_)));
+
+        navigation.budget = 0;
+        assert!(matches!(navigation.find_path(from, to), NavQuery::Found(_)));
+        assert!(matches!(
+            navigation.find_path(to, from),
+            NavQuery::Postponed
+        ));
+        assert!(matches!(
+            navigation.find_path(from, Vec2::new(0.0, 3.0)),
+            NavQuery::Direct
+        ));
+    }
+
+    #[test]
+    fn test_off_grid() {
+        let mut grid = NavGrid::new(10.0);
+        grid.block(&Obstacle::Circle { radius: 1.0 }, Vec2::ZERO);
+
+        let mut navigation = Navigation::default();
+        navigation.set_grid(grid);
+        navigation.reset_budget();
+
+        let inside = Vec2::new(-3.0, 0.0);
+        let outside = Vec2::new(8.0, 0.0);
+        assert!(matches!(
+            navigation.find_path(outside, inside),
+            NavQuery::Direct
+        ));
+        assert!(matches!(
+            navigation.find_path(inside, outside),
+            NavQuery::Found(_)
+        ));
+    }
+}
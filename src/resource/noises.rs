@@ -0,0 +1,98 @@
+use crate::model::Noise;
+use bevy::{ecs::system::Resource, math::Vec2};
+use std::{
+    collections::{HashMap, VecDeque},
+    time::Duration,
+};
+
+/// Recent noises. Kept for a while since bots don't listen every frame
+#[derive(Default, Resource)]
+pub struct Noises {
+    /// Noises heard from afar, ordered by time, the oldest first
+    loud: VecDeque<Noise>,
+    /// Quieter noises bucketed by position, so listeners skip those out of reach
+    quiet: HashMap<(i32, i32), VecDeque<Noise>>,
+}
+
+impl Noises {
+    const LIFETIME: Duration = Duration::from_secs(3);
+    /// Noises up to this loudness can only be heard from the neighboring buckets
+    const BUCKET_SIZE: f32 = 10.0;
+
+    pub fn add(&mut self, noise: Noise) {
+        let expiration = noise.time.saturating_sub(Self::LIFETIME);
+
+        let noises = if noise.loudness > Self::BUCKET_SIZE {
+            &mut self.loud
+        } else {
+            self.quiet
+                .entry(Self::to_bucket(noise.position))
+                .or_default()
+        };
+
+        while noises.front().map_or(false, |n| n.time <= expiration) {
+            noises.pop_front();
+        }
+
+        noises.push_back(noise);
+    }
+
+    /// Noises made after the given time which are loud enough to possibly be heard at the position
+    pub fn iter_near(&self, position: Vec2, since: Duration) -> impl Iterator<Item = &Noise> {
+        let (x, y) = Self::to_bucket(position);
+
+        let quiet = (-1..=1)
+            .flat_map(move |dy| (-1..=1).map(move |dx| (x + dx, y + dy)))
+            .filter_map(move |bucket| self.quiet.get(&bucket))
+            .flatten();
+
+        return self
+            .loud
+            .iter()
+            .chain(quiet)
+            .filter(move |n| n.time > since);
+    }
+
+    fn to_bucket(position: Vec2) -> (i32, i32) {
+        let bucket = (position / Self::BUCKET_SIZE).floor();
+        return (bucket.x as i32, bucket.y as i32);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn noise(seconds: u64, x: f32, loudness: f32) -> Noise {
+        return Noise {
+            position: Vec2::new(x, 0.0),
+            loudness,
+            emitter: None,
+            time: Duration::from_secs(seconds),
+        };
+    }
+
+    #[test]
+    fn test_expiration() {
+        let mut noises = Noises::default();
+        noises.add(noise(1, 0.0, 1.0));
+        noises.add(noise(2, 0.0, 1.0));
+        assert_eq!(noises.iter_near(Vec2::ZERO, Duration::ZERO).count(), 2);
+        assert_eq!(
+            noises.iter_near(Vec2::ZERO, Duration::from_secs(1)).count(),
+            1,
+        );
+
+        noises.add(noise(5, 0.0, 1.0));
+        assert_eq!(noises.iter_near(Vec2::ZERO, Duration::ZERO).count(), 1);
+    }
+
+    #[test]
+    fn test_range() {
+        let mut noises = Noises::default();
+        noises.add(noise(1, 9.0, 10.0));
+        noises.add(noise(1, 25.0, 10.0));
+        noises.add(noise(1, 50.0, 60.0));
+        assert_eq!(noises.iter_near(Vec2::ZERO, Duration::ZERO).count(), 2);
+    }
+}
@@ -0,0 +1,48 @@
+use anyhow::{bail, Result};
+use bevy::ecs::system::Resource;
+use std::{collections::HashMap, sync::Arc};
+
+/// Game data loaded from files and indexed by id. Keeps insertion order
+#[derive(Resource)]
+pub struct Registry<T> {
+    items: Vec<Arc<T>>,
+    ids: HashMap<String, usize>,
+}
+
+impl<T> Default for Registry<T> {
+    fn default() -> Self {
+        return Self {
+            items: Vec::new(),
+            ids: HashMap::new(),
+        };
+    }
+}
+
+impl<T> Registry<T> {
+    pub fn insert(&mut self, id: &str, item: T) -> Result<()> {
+        if self.ids.contains_key(id) {
+            bail!("Duplicate id: {}", id);
+        }
+
+        self.ids.insert(id.to_string(), self.items.len());
+        self.items.push(Arc::new(item));
+
+        return Ok(());
+    }
+
+    pub fn get(&self, id: &str) -> Option<&Arc<T>> {
+        return self.ids.get(id).and_then(|i| self.items.get(*i));
+    }
+
+    pub fn as_slice(&self) -> &[Arc<T>] {
+        return &self.items;
+    }
+
+    pub fn len(&self) -> usize {
+        return self.items.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.items.is_empty();
+    }
+}
@@ -1,6 +1,5 @@
 use crate::{
     command::{ActorBotSet, ActorPlayerSet, ActorSet, Exit, WeaponSet},
-    component::{ActorConfig, WeaponConfig},
     model::TransformLite,
     resource::ScenarioLogic,
 };
@@ -33,7 +32,7 @@ impl BenchScenario {
 
         commands.add(ActorSet {
             entity,
-            config: &ActorConfig::HUMAN,
+            actor: "human".into(),
             skill: 1.0,
             transform: TransformLite::default(),
         });
@@ -45,7 +44,7 @@ impl BenchScenario {
 
         commands.add(WeaponSet {
             entity,
-            weapon: Some(&WeaponConfig::PM),
+            weapon: Some("pm".into()),
         });
 
         self.spawned += 1;
@@ -57,7 +56,7 @@ impl BenchScenario {
 
             commands.add(ActorSet {
                 entity,
-                config: &ActorConfig::ZOMBIE,
+                actor: "zombie".into(),
                 skill: 1.0,
                 transform: TransformLite::default(),
             });
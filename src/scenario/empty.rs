@@ -1,6 +1,5 @@
 use crate::{
     command::{ActorPlayerSet, ActorSet, WeaponSet},
-    component::{ActorConfig, WeaponConfig},
     model::TransformLite,
     resource::ScenarioLogic,
 };
@@ -15,7 +14,7 @@ impl ScenarioLogic for EmptyScenario {
 
         commands.add(ActorSet {
             entity,
-            config: &ActorConfig::HUMAN,
+            actor: "human".into(),
             skill: 1.0,
             transform: TransformLite::default(),
         });
@@ -27,7 +26,7 @@ impl ScenarioLogic for EmptyScenario {
 
         commands.add(WeaponSet {
             entity,
-            weapon: Some(&WeaponConfig::PM),
+            weapon: Some("pm".into()),
         });
 
         return Duration::ZERO;
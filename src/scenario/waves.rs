@@ -1,7 +1,7 @@
 use crate::{
-    command::{ActorBotSet, ActorPlayerSet, ActorSet, BonusSpawn, Notify, WeaponSet},
-    component::{Actor, ActorConfig, ActorKind, Health, WeaponConfig},
-    data::VIEW_DISTANCE,
+    command::{ActorBotSet, ActorPlayerSet, ActorSet, AmmoAdd, BonusSpawn, Notify, WeaponSet},
+    component::{Actor, ActorKind, Bot, Health},
+    data::{VIEW_DISTANCE, WORLD_SIZE_HALF},
     event::ActorDeathEvent,
     model::TransformLite,
     resource::{Scenario, ScenarioLogic},
@@ -11,6 +11,7 @@ use bevy::{
     ecs::system::Command,
     math::{Vec2, Vec3Swizzles},
     prelude::{Commands, World},
+    time::Time,
     transform::components::Transform,
 };
 use rand::{Rng, SeedableRng};
@@ -76,7 +77,10 @@ const WAVE_BONUS: Wave = Wave {
 };
 
 const ZOMBIE_SPAWN_DISTANCE: f32 = VIEW_DISTANCE * 0.5;
+/// Distance from the world edge zombies spawn at least, to stay clear of the bluffs
+const ZOMBIE_SPAWN_MARGIN: f32 = 1.0;
 const BONUSES_PER_WAVE: f32 = 3.0;
+const PLAYER_AMMO: u16 = 24;
 const GAME_OVER_TEXT_DURATION: Duration = Duration::from_secs(8);
 const DEFAULT_INTERVAL: Duration = Duration::from_secs(2);
 
@@ -122,7 +126,7 @@ impl WavesScenario {
 
         commands.add(ActorSet {
             entity,
-            config: &ActorConfig::HUMAN,
+            actor: "human".into(),
             skill: 1.0,
             transform: TransformLite::default(),
         });
@@ -134,7 +138,13 @@ impl WavesScenario {
 
         commands.add(WeaponSet {
             entity,
-            weapon: Some(&WeaponConfig::PM),
+            weapon: Some("pm".into()),
+        });
+
+        commands.add(AmmoAdd {
+            entity,
+            caliber: "9x18".into(),
+            amount: PLAYER_AMMO,
         });
     }
 
@@ -169,16 +179,16 @@ impl WavesScenario {
                 let mut spawn = SpawnZombie {
                     skill: 1.0,
                     direction: self.rng.gen_range(-PI..PI),
-                    config: &ActorConfig::ZOMBIE,
+                    actor: "zombie",
                     weapon: None,
                 };
 
                 if self.rng.gen_bool(wave.agile_chance) {
-                    spawn.config = &ActorConfig::ZOMBIE_AGILE;
+                    spawn.actor = "zombie_agile";
                 } else if self.rng.gen_bool(wave.rifle_chance) {
-                    spawn.weapon = Some(&WeaponConfig::AKS_74U);
+                    spawn.weapon = Some("aks_74u");
                 } else if self.rng.gen_bool(wave.pistol_chance) {
-                    spawn.weapon = Some(&WeaponConfig::PM);
+                    spawn.weapon = Some("pm");
                 }
 
                 commands.add(spawn);
@@ -248,7 +258,9 @@ impl ScenarioLogic for WavesScenario {
                     }
                     1 => {
                         commands.add(Notify {
-                            text_small: "Press [SHIFT] to sprint".into(),
+                            text_small:
+                                "Press [SHIFT] to sprint. Hold [C] to crouch and step quietly"
+                                    .into(),
                             ..Default::default()
                         });
                     }
@@ -258,6 +270,32 @@ impl ScenarioLogic for WavesScenario {
                             ..Default::default()
                         });
                     }
+                    3 => {
+                        commands.add(Notify {
+                            text_small:
+                                "Press [1], [2] or use mouse wheel with [CTRL] to switch weapons"
+                                    .into(),
+                            ..Default::default()
+                        });
+                    }
+                    4 => {
+                        commands.add(Notify {
+                            text_small: "Press [Q] to throw a grenade".into(),
+                            ..Default::default()
+                        });
+                    }
+                    5 => {
+                        commands.add(Notify {
+                            text_small: "Hold [RMB] to aim down sights".into(),
+                            ..Default::default()
+                        });
+                    }
+                    6 => {
+                        commands.add(Notify {
+                            text_small: "Press [V] to bash with your weapon".into(),
+                            ..Default::default()
+                        });
+                    }
                     _ => {}
                 }
             }
@@ -274,10 +312,15 @@ impl ScenarioLogic for WavesScenario {
         }
     }
 
-    fn on_player_death(&mut self, _: &ActorDeathEvent, commands: &mut Commands) {
+    fn on_player_death(&mut self, event: &ActorDeathEvent, commands: &mut Commands) {
+        let cause = event
+            .attack
+            .as_ref()
+            .map_or(String::new(), |a| format!(" by {}", a.get_name()));
+
         commands.add(Notify {
             text: "Game over".into(),
-            text_small: "You died. Press [ESC] to exit".into(),
+            text_small: format!("You were killed{}. Press [ESC] to exit", cause).into(),
             duration: GAME_OVER_TEXT_DURATION,
         });
     }
@@ -303,8 +346,8 @@ struct Wave {
 struct SpawnZombie {
     skill: f32,
     direction: f32,
-    config: &'static ActorConfig,
-    weapon: Option<&'static WeaponConfig>,
+    actor: &'static str,
+    weapon: Option<&'static str>,
 }
 
 impl Command for SpawnZombie {
@@ -324,13 +367,14 @@ impl Command for SpawnZombie {
         }
 
         let entity = world.spawn_empty().id();
-        let offset = Vec2::from_length(ZOMBIE_SPAWN_DISTANCE, self.direction);
-        let transform =
-            TransformLite::new(center.x + offset.x, center.y + offset.y, self.direction);
+        let limit = Vec2::splat(WORLD_SIZE_HALF - ZOMBIE_SPAWN_MARGIN);
+        let position = (center + Vec2::from_length(ZOMBIE_SPAWN_DISTANCE, self.direction))
+            .clamp(-limit, limit);
+        let transform = TransformLite::new(position.x, position.y, self.direction);
 
         ActorSet {
             entity,
-            config: self.config,
+            actor: self.actor.into(),
             skill: self.skill,
             transform,
         }
@@ -342,10 +386,19 @@ impl Command for SpawnZombie {
         }
         .apply(world);
 
+        if humans > 0.0 {
+            // zombies smell humans from afar, so let them come even unseen
+            let time = world.resource::<Time>().elapsed();
+
+            if let Some(mut bot) = world.get_mut::<Bot>(entity) {
+                bot.search(center, time);
+            }
+        }
+
         if let Some(weapon) = self.weapon {
             WeaponSet {
                 entity,
-                weapon: Some(weapon),
+                weapon: Some(weapon.into()),
             }
             .apply(world);
         }
@@ -1,10 +1,15 @@
 use crate::{
-    component::{Actor, Bot},
-    util::ext::RngExt,
+    component::{Actor, Bot, Grenades},
+    data::BotConfig,
+    resource::{Factions, Navigation, Noises},
+    util::{
+        ext::{RngExt, TransformExt, Vec2Ext},
+        math::angle_difference,
+    },
 };
 use bevy::{
     ecs::query::BatchingStrategy,
-    math::Vec3Swizzles,
+    math::{Vec2, Vec3Swizzles},
     prelude::{Entity, Query, Res, Transform},
     time::Time,
 };
@@ -14,15 +19,18 @@ const TEAMMATES_MAX: usize = 8;
 const UPDATE_INTERVAL: Duration = Duration::from_millis(1500);
 
 pub fn analyze(
-    mut bots: Query<(&mut Bot, Entity, &Actor, &Transform)>,
+    mut bots: Query<(&mut Bot, Entity, &Actor, &Transform, Option<&Grenades>)>,
     actors: Query<(Entity, &Actor, &Transform)>,
+    navigation: Res<Navigation>,
+    factions: Res<Factions>,
+    noises: Res<Noises>,
     time: Res<Time>,
 ) {
     let time = time.elapsed();
 
     bots.par_iter_mut()
         .batching_strategy(BatchingStrategy::fixed(32))
-        .for_each(|(mut bot, e1, a1, t1)| {
+        .for_each(|(mut bot, e1, a1, t1, grenades)| {
             if !bot.update_timer.is_ready_or_disabled(time) {
                 return;
             }
@@ -30,9 +38,10 @@ pub fn analyze(
             let update_interval_fuzzed = bot.rng.fuzz_duration(UPDATE_INTERVAL);
             bot.update_timer.set(time + update_interval_fuzzed);
             bot.update_idle();
-            bot.enemy = None;
+            let had_enemy = bot.enemy.take().is_some();
 
             let p1 = t1.translation.xy();
+            let direction = t1.direction();
 
             let mut enemy_distance = f32::MAX;
             let mut teammates = Teammates::new();
@@ -44,7 +53,7 @@ pub fn analyze(
 
                 let distance = p1.distance_squared(t2.translation.truncate());
 
-                if a1.config.kind == a2.config.kind {
+                if factions.is_allied(a1.faction, a2.faction) {
                     // later teammate can come closer. so we store it event if it's outsize the
                     // spread radius
                     let distance_max = bot.config.spread * 2.0;
@@ -52,16 +61,116 @@ pub fn analyze(
                     if distance < distance_max * distance_max {
                         teammates.try_add(e2, distance);
                     }
-                } else if distance < enemy_distance {
+                } else if factions.is_hostile(a1.faction, a2.faction)
+                    && distance < enemy_distance
+                    && can_see(&bot.config, p1, direction, t2.translation.xy(), &navigation)
+                {
                     bot.enemy = Some(e2);
                     enemy_distance = distance;
                 }
             }
 
+            if had_enemy && bot.enemy.is_none() {
+                // lost sight of the enemy. go check where it was seen the last time
+                if let Some(position) = bot.enemy_last_position {
+                    bot.search(position, time);
+                }
+            }
+
+            if bot.enemy.is_none() {
+                if let Some(position) = listen(a1, p1, bot.listened, &noises, &actors, &factions) {
+                    // heard something. go check what it was
+                    bot.search(position, time);
+                }
+            }
+
+            bot.listened = time;
+
             bot.teammates = teammates.teammates;
+            bot.enemy_group = 0;
+
+            if grenades.map_or(false, |g| g.count > 0) {
+                if let Some(enemy) = bot.enemy.and_then(|e| actors.get(e).ok()) {
+                    bot.enemy_group = count_group(a1, enemy.2.translation.xy(), &actors, &factions);
+                }
+            }
         });
 }
 
+/// Whether the bot can see the target: within its view range and cone, and not behind an obstacle
+fn can_see(
+    config: &BotConfig,
+    position: Vec2,
+    direction: f32,
+    target: Vec2,
+    navigation: &Navigation,
+) -> bool {
+    if target.is_far(position, config.view_distance) {
+        return false;
+    }
+
+    if target.is_far(position, BotConfig::SENSE_DISTANCE)
+        && angle_difference(direction, position.angle_to(target)).abs() > config.view_angle / 2.0
+    {
+        return false;
+    }
+
+    return navigation.is_visible(position, target);
+}
+
+/// Finds the position of the most noticeable noise made by anyone but allies since the given time
+fn listen(
+    actor: &Actor,
+    position: Vec2,
+    since: Duration,
+    noises: &Noises,
+    actors: &Query<(Entity, &Actor, &Transform)>,
+    factions: &Factions,
+) -> Option<Vec2> {
+    let mut heard = None;
+    let mut excess_max = 0.0;
+
+    for noise in noises.iter_near(position, since) {
+        let excess = noise.loudness - noise.position.distance(position);
+
+        if excess <= excess_max {
+            continue;
+        }
+
+        let is_allied = noise
+            .emitter
+            .and_then(|e| actors.get(e).ok())
+            .map_or(false, |(_, a, _)| {
+                factions.is_allied(actor.faction, a.faction)
+            });
+
+        if !is_allied {
+            heard = Some(noise.position);
+            excess_max = excess;
+        }
+    }
+
+    return heard;
+}
+
+/// Counts enemies of the given actor standing close to the position
+fn count_group(
+    actor: &Actor,
+    position: Vec2,
+    actors: &Query<(Entity, &Actor, &Transform)>,
+    factions: &Factions,
+) -> usize {
+    return actors
+        .iter()
+        .filter(|(_, a, t)| {
+            factions.is_hostile(a.faction, actor.faction)
+                && t.translation
+                    .xy()
+                    .is_close(position, BotConfig::GRENADE_GROUP_RADIUS)
+        })
+        .count();
+}
+
 struct Teammates {
     teammates: Vec<Entity>,
     distances: Vec<f32>,
@@ -105,3 +214,45 @@ impl Teammates {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        component::Obstacle,
+        data::{load_actors, ACTORS_PATH},
+        model::NavGrid,
+    };
+    use std::f32::consts::PI;
+
+    #[test]
+    fn test_can_see() {
+        let actors = load_actors(ACTORS_PATH).expect("Failed to load actors");
+        let config = &actors.get("human").expect("No human archetype").bot;
+        let mut grid = NavGrid::new(20.0);
+        grid.block(&Obstacle::Circle { radius: 1.0 }, Vec2::ZERO);
+
+        let mut navigation = Navigation::default();
+        navigation.set_grid(grid);
+
+        let position = Vec2::new(-5.0, 5.0);
+        let ahead = Vec2::new(5.0, 5.0);
+        let near = Vec2::new(-5.0 + BotConfig::SENSE_DISTANCE / 2.0, 5.0);
+        let far = Vec2::new(-5.0 + config.view_distance * 2.0, 5.0);
+
+        // range
+        assert!(can_see(config, position, 0.0, ahead, &navigation));
+        assert!(!can_see(config, position, 0.0, far, &navigation));
+
+        // cone
+        assert!(!can_see(config, position, PI, ahead, &navigation));
+        assert!(can_see(config, position, PI, near, &navigation));
+
+        // occlusion
+        let position = Vec2::new(-5.0, 0.0);
+        let front = Vec2::new(-1.5, 0.0);
+        let behind = Vec2::new(5.0, 0.0);
+        assert!(can_see(config, position, 0.0, front, &navigation));
+        assert!(!can_see(config, position, 0.0, behind, &navigation));
+    }
+}
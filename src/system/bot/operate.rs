@@ -1,33 +1,51 @@
 use crate::{
-    component::{Actor, Bot, BotShootingState, Inertia, Weapon},
+    component::{
+        Actor, Bot, BotShootingState, FireMode, Grenades, Inertia, Weapon, WeaponAttachment,
+    },
     data::BotConfig,
-    model::ActorAction,
+    model::{ActorAction, NavPath},
+    resource::{NavQuery, Navigation},
     util::{
-        ext::{TransformExt, Vec2Ext},
+        ext::{RngExt, TransformExt, Vec2Ext},
         math::angle_difference,
         traits::{WithPosition, WithPositionAndVelocity, WithVelocity},
         GIZMOS,
     },
 };
 use bevy::{
-    ecs::system::Res,
+    ecs::system::{Res, ResMut},
     math::{Vec2, Vec3Swizzles},
     prelude::{Color, Query, Transform, With},
     time::Time,
 };
-use std::{f32::consts::FRAC_PI_2, ops::Div, time::Duration};
+use std::{
+    f32::consts::{FRAC_PI_2, PI},
+    ops::Div,
+    sync::Arc,
+    time::Duration,
+};
 
 const DEBUG_TEAMMATES: bool = false;
 const DEBUG_AIM: bool = false;
+const DEBUG_PATH: bool = false;
 
 pub fn operate(
-    mut bots: Query<(&mut Bot, &mut Actor, &Transform, &Inertia, Option<&Weapon>)>,
+    mut bots: Query<(
+        &mut Bot,
+        &mut Actor,
+        &Transform,
+        &Inertia,
+        Option<&mut Weapon>,
+        Option<&mut Grenades>,
+    )>,
     actors: Query<(&Transform, &Inertia), With<Actor>>,
+    mut navigation: ResMut<Navigation>,
     time: Res<Time>,
 ) {
     let time = time.elapsed();
+    navigation.reset_budget();
 
-    for (mut bot, mut actor, transform, inertia, weapon) in bots.iter_mut() {
+    for (mut bot, mut actor, transform, inertia, weapon, mut grenades) in bots.iter_mut() {
         actor.reset_actions();
 
         let enemy = bot
@@ -43,9 +61,14 @@ pub fn operate(
             // enemy no longer exists. force new enemy search now
             let reaction = bot.config.reaction;
             bot.enemy = None;
+            bot.stop_search();
             bot.update_timer.set(time + reaction);
         }
 
+        if let Some(enemy) = &enemy {
+            bot.enemy_last_position = Some(enemy.position);
+        }
+
         let mut handler = BotHandler {
             bot: &mut bot,
             actor: &mut actor,
@@ -53,20 +76,35 @@ pub fn operate(
             velocity: inertia.velocity,
             spread_out: SpreadOut::Full,
             is_dodging: false,
+            has_laser_sight: weapon
+                .as_ref()
+                .map_or(false, |w| w.has_attachment(WeaponAttachment::LaserSight)),
+            navigation: &mut navigation,
+            time,
         };
 
+        let melee_reach = weapon
+            .as_ref()
+            .and_then(|w| w.config.melee.as_ref())
+            .map(|m| m.reach);
+
         if let Some(enemy) = enemy {
             if handler.bot.config.is_agile {
                 handler.dodge_enemy(&enemy);
             }
 
             if !handler.is_dodging {
-                if let Some(weapon) = weapon {
-                    handler.attack_enemy_armed(&enemy, weapon, time);
+                if let Some(reach) = melee_reach {
+                    handler.attack_enemy_melee(&enemy, reach);
+                } else if let Some(mut weapon) = weapon {
+                    handler.attack_enemy_armed(&enemy, &mut weapon, grenades.as_deref_mut(), time);
                 } else {
-                    handler.attack_enemy_melee(&enemy);
+                    let reach = handler.actor.config.melee_distance;
+                    handler.attack_enemy_melee(&enemy, reach);
                 }
             }
+        } else if let Some(position) = handler.bot.enemy_last_position {
+            handler.search(position, time);
         } else {
             handler.idle();
         }
@@ -90,6 +128,9 @@ struct BotHandler<'a> {
     velocity: Vec2,
     spread_out: SpreadOut,
     is_dodging: bool,
+    has_laser_sight: bool,
+    navigation: &'a mut Navigation,
+    time: Duration,
 }
 
 impl<'a> BotHandler<'a> {
@@ -117,7 +158,13 @@ impl<'a> BotHandler<'a> {
         }
     }
 
-    fn attack_enemy_armed(&mut self, target: &BotTarget, weapon: &Weapon, time: Duration) {
+    fn attack_enemy_armed(
+        &mut self,
+        target: &BotTarget,
+        weapon: &mut Weapon,
+        grenades: Option<&mut Grenades>,
+        time: Duration,
+    ) {
         if self.is_close(&target.position, self.bot.config.shoot_distance_min) {
             // don't come too close
             self.actor.movement += Vec2::BACK / 1.5;
@@ -126,6 +173,17 @@ impl<'a> BotHandler<'a> {
 
         if !weapon.is_armed() {
             self.actor.actions |= ActorAction::Reload;
+            self.bash(target);
+            return;
+        }
+
+        if weapon.is_reloading() {
+            self.bash(target);
+        }
+
+        if weapon.is_overheated(time) {
+            // let it cool down
+            self.bot.set_shooting_target(false, time);
             return;
         }
 
@@ -134,6 +192,13 @@ impl<'a> BotHandler<'a> {
                 self.spread_out = SpreadOut::Restricted;
             }
 
+            if let Some(grenades) = grenades {
+                if self.throw_grenade(target, grenades, time) {
+                    self.bot.set_shooting_target(false, time);
+                    return;
+                }
+            }
+
             if self.bot.config.is_silly
                 && self.is_far(&target.position, self.bot.config.shoot_distance_min * 1.25)
             {
@@ -142,17 +207,17 @@ impl<'a> BotHandler<'a> {
             }
 
             self.bot.set_shooting_target(true, time);
+            self.select_fire_mode(target, weapon);
 
-            let shooting_state = self
-                .bot
-                .get_shooting_state(weapon.config.is_automatic, time);
+            let is_continuous_fire = weapon.get_fire_mode().is_continuous();
+            let shooting_state = self.bot.get_shooting_state(is_continuous_fire, time);
 
             let debug_color;
             let is_aimed = self.is_aimed_at(target.position);
 
             if shooting_state == BotShootingState::Shoot && (is_aimed || self.bot.was_burst_fire) {
                 self.actor.actions |= ActorAction::Attack;
-                self.bot.was_burst_fire = weapon.config.is_automatic;
+                self.bot.was_burst_fire = is_continuous_fire;
                 debug_color = Color::RED;
             } else {
                 // keep aim ony while not attacking, otherwise recoil won't work
@@ -193,10 +258,57 @@ impl<'a> BotHandler<'a> {
         }
     }
 
-    fn attack_enemy_melee(&mut self, target: &BotTarget) {
+    /// Throws a grenade if the target is among other enemies and within the throw range.
+    /// Returns false if the bot doesn't want to
+    fn throw_grenade(
+        &mut self,
+        target: &BotTarget,
+        grenades: &mut Grenades,
+        time: Duration,
+    ) -> bool {
+        if self.bot.enemy_group < BotConfig::GRENADE_GROUP_MIN
+            || !grenades.is_ready(time)
+            || self.is_close(&target.position, grenades.config.radius)
+            || self.is_far(&target.position, grenades.config.get_throw_distance())
+        {
+            return false;
+        }
+
+        self.look_at_position(target.position);
+
+        if self.is_aimed_at(target.position) {
+            grenades.target_distance = Some(self.distance_squared(&target.position).sqrt());
+            self.actor.actions |= ActorAction::Throw;
+        }
+
+        return true;
+    }
+
+    fn select_fire_mode(&self, target: &BotTarget, weapon: &mut Weapon) {
+        if self.is_close(&target.position, self.bot.config.auto_fire_distance) {
+            if !weapon.set_fire_mode(FireMode::Auto) {
+                weapon.set_fire_mode(FireMode::Burst);
+            }
+        } else {
+            weapon.set_fire_mode(FireMode::Semi);
+        }
+    }
+
+    /// Hits the target with the weapon stock if it's in reach, useful while out of ammo
+    fn bash(&mut self, target: &BotTarget) {
         // TODO: count enemy body radius instead of self
         let melee_distance = self.actor.config.melee_distance + self.actor.config.radius;
 
+        if self.is_close(&target.position, melee_distance) {
+            self.look_at_position(target.position);
+            self.actor.actions |= ActorAction::Bash;
+        }
+    }
+
+    fn attack_enemy_melee(&mut self, target: &BotTarget, reach: f32) {
+        // TODO: count enemy body radius instead of self
+        let melee_distance = reach + self.actor.config.radius;
+
         if self.is_close(&target.position, melee_distance) {
             // enemy is close, attack
             self.look_at_position(target.position);
@@ -222,18 +334,88 @@ impl<'a> BotHandler<'a> {
             self.actor.actions |= ActorAction::Sprint;
         }
 
-        self.look_at_position(meet);
+        let waypoint = self.navigate(meet).unwrap_or(meet);
+        self.look_at_position(waypoint);
         self.actor.movement += Vec2::FRONT;
     }
 
+    /// Goes to where the enemy was seen the last time. Gives up once there or after a while
+    fn search(&mut self, position: Vec2, time: Duration) {
+        if self.bot.search_timer.is_ready_and_enabled(time)
+            || self.is_close(&position, BotConfig::SEARCH_REACH)
+        {
+            self.bot.stop_search();
+            self.idle();
+            return;
+        }
+
+        self.chase(&BotTarget {
+            position,
+            velocity: Vec2::ZERO,
+            direction: 0.0,
+        });
+    }
+
     fn idle(&mut self) {
-        self.look_at_direction(self.bot.idle_direction);
+        if !self.bot.idle_movement {
+            self.look_at_direction(self.bot.idle_direction);
+            return;
+        }
+
+        let goal = self.position()
+            + Vec2::from_length(BotConfig::IDLE_WALK_DISTANCE, self.bot.idle_direction);
 
-        if self.bot.idle_movement {
+        if let Some(waypoint) = self.navigate(goal) {
+            self.look_at_position(waypoint);
             self.actor.movement += Vec2::FRONT;
+        } else {
+            // walked into a dead end, turn back
+            self.bot.idle_direction += PI;
+            self.look_at_direction(self.bot.idle_direction);
         }
     }
 
+    /// Finds where to head to reach the goal around obstacles. Returns None if there is no way
+    fn navigate(&mut self, goal: Vec2) -> Option<Vec2> {
+        let position = self.position();
+
+        let is_goal_moved = self
+            .bot
+            .path
+            .get_goal()
+            .map_or(true, |g| g.is_far(goal, BotConfig::REPATH_DISTANCE));
+
+        if is_goal_moved || self.bot.path_timer.is_ready_or_disabled(self.time) {
+            let path = match self.navigation.find_path(position, goal) {
+                NavQuery::Direct => Some(NavPath::new(goal, Some(Arc::from(Vec::new())))),
+                NavQuery::Found(waypoints) => Some(NavPath::new(goal, Some(waypoints))),
+                NavQuery::Unreachable => Some(NavPath::new(goal, None)),
+                // out of searches, retry on the next frame
+                NavQuery::Postponed => None,
+            };
+
+            if let Some(path) = path {
+                let interval = self.bot.rng.fuzz_duration(BotConfig::REPATH_INTERVAL);
+                self.bot.path = path;
+                self.bot.path_timer.set(self.time + interval);
+            } else if is_goal_moved {
+                // the current path leads elsewhere, head straight meanwhile
+                return Some(goal);
+            }
+        }
+
+        if DEBUG_PATH {
+            let mut from = position;
+
+            for waypoint in self.bot.path.iter_waypoints() {
+                GIZMOS.ln(from, *waypoint, Color::BLUE);
+                from = *waypoint;
+            }
+        }
+
+        return self.bot.path.next_waypoint(position, goal);
+    }
+
     fn spread_out(&mut self, is_full: bool, actors: &Query<(&Transform, &Inertia), With<Actor>>) {
         let mut teammates_position_sum = Vec2::ZERO;
         let mut teammates_position_sum_weight = 0.0;
@@ -302,8 +484,15 @@ impl<'a> BotHandler<'a> {
     }
 
     fn is_aimed_at(&self, target: Vec2) -> bool {
+        let mut deviation = self.bot.config.angular_deviation;
+
+        // the laser dot shows exactly where the weapon points
+        if self.has_laser_sight {
+            deviation *= BotConfig::LASER_SIGHT_AIM_FACTOR;
+        }
+
         return angle_difference(self.transform.direction(), self.angle_to(&target)).abs()
-            < self.bot.config.angular_deviation;
+            < deviation;
     }
 
     fn can_sprint(&self) -> bool {
@@ -1,5 +1,5 @@
 use crate::{
-    component::{Actor, Inertia},
+    component::{Actor, Inertia, StatusEffects},
     model::ActorActionsExt,
     util::{
         ext::{TransformExt, Vec2Ext},
@@ -15,10 +15,18 @@ use bevy::{
 
 const TURN_EPSILON: f32 = 0.01;
 
-pub fn actor(mut query: Query<(&mut Actor, &mut Transform, &mut Inertia)>, time: Res<Time>) {
+pub fn actor(
+    mut query: Query<(
+        &mut Actor,
+        &mut Transform,
+        &mut Inertia,
+        Option<&StatusEffects>,
+    )>,
+    time: Res<Time>,
+) {
     let time_delta = time.delta_seconds();
 
-    for (mut actor, mut transform, mut inertia) in query.iter_mut() {
+    for (mut actor, mut transform, mut inertia, effects) in query.iter_mut() {
         actor.update_stamina(time_delta);
         turn(&actor, &mut transform, &mut inertia, time_delta);
 
@@ -36,6 +44,20 @@ pub fn actor(mut query: Query<(&mut Actor, &mut Transform, &mut Inertia)>, time:
             movement *= actor.config.sprint_factor;
         }
 
+        if actor.actions.is_aiming() {
+            movement *= Actor::AIM_MOVEMENT_FACTOR;
+        }
+
+        if actor.actions.is_crouching() {
+            movement *= Actor::CROUCH_MOVEMENT_FACTOR;
+        }
+
+        movement *= 1.0 - actor.leg_wound * Actor::LEG_WOUND_SLOWDOWN;
+
+        if let Some(effects) = effects {
+            movement *= effects.get_movement_factor();
+        }
+
         inertia.push(movement, 0.0, true, false);
     }
 }
@@ -0,0 +1,23 @@
+use crate::{component::Beam, util::ext::DurationExt};
+use bevy::{
+    ecs::system::Query,
+    prelude::{Commands, DespawnRecursiveExt, Entity, Res, Time, Transform},
+};
+
+pub fn beam(
+    mut beams: Query<(Entity, &Beam, &mut Transform)>,
+    mut commands: Commands,
+    time: Res<Time>,
+) {
+    let time = time.elapsed();
+
+    for (entity, beam, mut transform) in beams.iter_mut() {
+        let progress = time.progress(beam.spawned, beam.spawned + Beam::DURATION);
+
+        if progress >= 1.0 {
+            commands.entity(entity).despawn_recursive();
+        } else {
+            transform.scale.y = Beam::THICKNESS * (1.0 - progress);
+        }
+    }
+}
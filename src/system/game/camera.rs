@@ -1,4 +1,8 @@
-use crate::{component::Player, data::VIEW_DISTANCE, util::ext::TransformExt};
+use crate::{
+    component::{Player, Weapon},
+    data::VIEW_DISTANCE,
+    util::{ext::TransformExt, math::interpolate},
+};
 use bevy::{
     ecs::system::Query,
     math::{Quat, Vec2, Vec3},
@@ -8,12 +12,16 @@ use bevy::{
 use std::f32::consts::FRAC_PI_2;
 
 const OFFSET_RATIO: f32 = 0.25;
+const OFFSET_RATIO_AIM: f32 = 0.35;
 const SHAKE_FACTOR_Y: f32 = 4.0;
 const SHAKE_FACTOR_Z: f32 = 0.6;
 
 pub fn camera(
     mut cameras: Query<(&mut Transform, &mut OrthographicProjection)>,
-    players: Query<(&Player, &Transform), (Without<Camera>, Without<OrthographicProjection>)>,
+    players: Query<
+        (&Player, &Transform, Option<&Weapon>),
+        (Without<Camera>, Without<OrthographicProjection>),
+    >,
     windows: Query<&Window, With<PrimaryWindow>>,
 ) {
     let window_size = if let Some(window) = windows.iter().next() {
@@ -22,17 +30,21 @@ pub fn camera(
         return;
     };
 
-    if let Some((player, player_transform)) = players.iter().next() {
+    if let Some((player, player_transform, weapon)) = players.iter().next() {
+        let aim = player.get_aim();
+        let aim_zoom = weapon.map_or(0.0, |w| w.config.aim_zoom) * aim;
+        let base_zoom = player.get_zoom() * (1.0 + aim_zoom);
         let shake = player.get_shake();
         let shake_y = player.get_shake_abs() * SHAKE_FACTOR_Y;
-        let shake_z = player.get_shake_abs() * SHAKE_FACTOR_Z * player.get_zoom();
-        let zoom = player.get_zoom() - shake_z;
+        let shake_z = player.get_shake_abs() * SHAKE_FACTOR_Z * base_zoom;
+        let zoom = base_zoom - shake_z;
+        let offset_ratio = interpolate(OFFSET_RATIO, OFFSET_RATIO_AIM, aim);
         let direction = player_transform.direction() + shake - player.get_extra_rotation();
 
         if let Some((mut transform, mut projection)) = cameras.iter_mut().next() {
             projection.scale = VIEW_DISTANCE / zoom / window_size.length();
             let rotation = Quat::from_rotation_z(direction - FRAC_PI_2);
-            let offset_y = window_size.y * projection.scale * OFFSET_RATIO - shake_y;
+            let offset_y = window_size.y * projection.scale * offset_ratio - shake_y;
             let offset = rotation * Vec3::new(0.0, offset_y, 0.0);
             transform.translation.x = player_transform.translation.x + offset.x;
             transform.translation.y = player_transform.translation.y + offset.y;
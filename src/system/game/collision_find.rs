@@ -1,4 +1,4 @@
-use crate::component::{Collision, CollisionSolution, Inertia};
+use crate::component::{Collision, CollisionSolution, Inertia, Obstacle};
 use bevy::{
     ecs::system::Local,
     math::{Vec2, Vec3Swizzles},
@@ -13,6 +13,7 @@ pub struct CollisionFindSystemData {
 pub fn collision_find(
     mut data: Local<CollisionFindSystemData>,
     query: Query<(Entity, &Collision, &Transform, &Inertia)>,
+    obstacles: Query<(&Obstacle, &Transform)>,
 ) -> Vec<CollisionSolution> {
     let mut solutions = Vec::with_capacity(data.previous_solutions);
 
@@ -30,6 +31,19 @@ pub fn collision_find(
         }
     }
 
+    for (entity, collision, transform, inertia) in query.iter() {
+        for (obstacle, obstacle_transform) in obstacles.iter() {
+            if let Some((shift, normal)) = obstacle.resolve(
+                obstacle_transform.translation.xy(),
+                transform.translation.xy(),
+                collision.radius,
+            ) {
+                let push = inertia.bounce_static(normal);
+                append_solution(&mut solutions, entity, shift, push);
+            }
+        }
+    }
+
     data.previous_solutions = solutions.len();
 
     return solutions;
@@ -1,5 +1,11 @@
-use crate::component::{Collision, CollisionSolution, Inertia};
-use bevy::prelude::{In, Query, Transform, With};
+use crate::{
+    component::{Collision, CollisionSolution, Inertia},
+    data::WORLD_SIZE_HALF,
+};
+use bevy::{
+    math::Vec3Swizzles,
+    prelude::{In, Query, Transform, Vec2, With},
+};
 
 pub fn collision_resolve(
     In(mut solutions): In<Vec<CollisionSolution>>,
@@ -12,4 +18,17 @@ pub fn collision_resolve(
             transform.translation.y += solution.shift.y;
         }
     }
+
+    // keep everyone within the world. Only those outside are written to spare change detection
+    let limit = Vec2::splat(WORLD_SIZE_HALF);
+
+    for (mut transform, _) in query.iter_mut() {
+        let position = transform.translation.xy();
+        let clamped = position.clamp(-limit, limit);
+
+        if clamped != position {
+            transform.translation.x = clamped.x;
+            transform.translation.y = clamped.y;
+        }
+    }
 }
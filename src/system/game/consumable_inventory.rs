@@ -0,0 +1,65 @@
+use crate::{
+    command::Notify,
+    component::{Actor, Consumable, ConsumableInventory, Health, StatusEffects},
+    model::ActorAction,
+};
+use bevy::prelude::{Commands, Query, Res, Time};
+
+pub fn consumable_inventory(
+    mut query: Query<(
+        &mut ConsumableInventory,
+        &mut Actor,
+        &mut Health,
+        Option<&mut StatusEffects>,
+    )>,
+    mut commands: Commands,
+    time: Res<Time>,
+) {
+    let now = time.elapsed();
+
+    for (mut inventory, mut actor, mut health, mut effects) in query.iter_mut() {
+        if let Some(item) = inventory.take_used(now) {
+            apply(item, &mut actor, &mut health, effects.as_deref_mut());
+        } else if let Some(item) = inventory.take_request() {
+            inventory.start_using(item, now);
+
+            commands.add(Notify {
+                text_small: format!("Using {}...", item.name().to_lowercase()).into(),
+                ..Default::default()
+            });
+        }
+
+        if inventory.is_using() {
+            // hands are busy
+            actor.actions.remove(ActorAction::Attack);
+            actor.actions.remove(ActorAction::Reload);
+            actor.actions.remove(ActorAction::Throw);
+            actor.actions.remove(ActorAction::Aim);
+            actor.actions.remove(ActorAction::Bash);
+        }
+    }
+}
+
+fn apply(
+    item: Consumable,
+    actor: &mut Actor,
+    health: &mut Health,
+    effects: Option<&mut StatusEffects>,
+) {
+    health.restore(item.get_healing());
+
+    if let Some(effects) = effects {
+        effects.cure(item.get_cures());
+    }
+
+    match item {
+        Consumable::Bandage | Consumable::Medkit => {}
+        Consumable::Painkillers => {
+            actor.arm_wound = 0.0;
+            actor.leg_wound = 0.0;
+        }
+        Consumable::EnergyDrink => {
+            actor.stamina = 1.0;
+        }
+    }
+}
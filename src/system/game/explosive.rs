@@ -0,0 +1,207 @@
+use crate::{
+    command::{ProjectileSpawn, ScorchSpawn},
+    component::{Actor, Explosive, Obstacle, StatusEffect, StatusEffects},
+    data::LAYER_PROJECTILE,
+    model::{Attack, AudioPlay, HitSource, HitZone, Noise, TransformLite},
+    resource::{AudioTracker, Config, Factions, FriendlyFire, HitResource, Noises},
+    util::ext::Vec2Ext,
+};
+use bevy::{
+    ecs::system::{Local, Query},
+    math::{Vec2, Vec3, Vec3Swizzles},
+    prelude::{Commands, DespawnRecursiveExt, Entity, Res, ResMut, Time, Transform, Without},
+};
+use rand::{Rng, SeedableRng};
+use rand_pcg::Pcg32;
+use std::{f32::consts::TAU, sync::Arc, time::Duration};
+
+const HEIGHT_SCALE: f32 = 0.15;
+const SCORCH_SCALE: f32 = 0.4;
+const FRAGMENT_VELOCITY_MIN: f32 = 0.6;
+const CONCUSSION_SLOWDOWN: f32 = 0.5;
+const CONCUSSION_DURATION: Duration = Duration::from_secs(3);
+const BURNING_DURATION: Duration = Duration::from_secs(5);
+/// Distance bots can hear explosions from, meters
+const EXPLOSION_NOISE: f32 = 100.0;
+
+pub struct ExplosiveSystemData {
+    rng: Pcg32,
+}
+
+impl Default for ExplosiveSystemData {
+    fn default() -> Self {
+        return Self {
+            rng: Pcg32::seed_from_u64(0),
+        };
+    }
+}
+
+pub fn explosive(
+    mut data: Local<ExplosiveSystemData>,
+    mut explosives: Query<(Entity, &mut Explosive, &mut Transform)>,
+    actors: Query<(Entity, &Actor, &Transform), Without<Explosive>>,
+    mut effects: Query<&mut StatusEffects>,
+    statics: Query<(&Obstacle, &Transform), Without<Explosive>>,
+    mut hits: ResMut<HitResource>,
+    mut noises: ResMut<Noises>,
+    mut commands: Commands,
+    audio: Res<AudioTracker>,
+    time: Res<Time>,
+    config: Res<Config>,
+    factions: Res<Factions>,
+) {
+    let now = time.elapsed();
+    let delta = time.delta_seconds();
+
+    for (entity, mut explosive, mut transform) in explosives.iter_mut() {
+        let mut position = transform.translation.xy();
+
+        if explosive.is_ready(now) {
+            explode(
+                &explosive,
+                position,
+                &actors,
+                &mut effects,
+                &statics,
+                &mut hits,
+                &mut commands,
+                &mut data.rng,
+                now,
+                config.game.friendly_fire,
+                &factions,
+            );
+
+            audio.queue(AudioPlay {
+                path: "sounds/explosion".into(),
+                volume: 1.5,
+                source: Some(position),
+                ..AudioPlay::DEFAULT
+            });
+
+            noises.add(Noise {
+                position,
+                loudness: EXPLOSION_NOISE,
+                emitter: explosive.thrower,
+                time: now,
+            });
+
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+
+        position += explosive.update(delta);
+
+        for (obstacle, obstacle_transform) in statics.iter() {
+            if let Some((shift, normal)) = obstacle.resolve(
+                obstacle_transform.translation.xy(),
+                position,
+                Explosive::RADIUS,
+            ) {
+                position += shift;
+                explosive.bounce(normal);
+            }
+        }
+
+        transform.translation = position.extend(LAYER_PROJECTILE);
+        transform.scale = Vec3::splat(1.0 + explosive.height * HEIGHT_SCALE);
+    }
+}
+
+fn explode(
+    explosive: &Explosive,
+    center: Vec2,
+    actors: &Query<(Entity, &Actor, &Transform), Without<Explosive>>,
+    effects: &mut Query<&mut StatusEffects>,
+    statics: &Query<(&Obstacle, &Transform), Without<Explosive>>,
+    hits: &mut HitResource,
+    commands: &mut Commands,
+    rng: &mut Pcg32,
+    time: Duration,
+    friendly_fire: FriendlyFire,
+    factions: &Factions,
+) {
+    let config = &explosive.config;
+
+    let thrower = explosive
+        .thrower
+        .and_then(|e| actors.get(e).ok())
+        .map(|a| a.1);
+
+    for (entity, actor, transform) in actors.iter() {
+        // friendly fire never spares the thrower from their own blast
+        if explosive.thrower != Some(entity) && friendly_fire.spares(factions, thrower, actor) {
+            continue;
+        }
+
+        let position = transform.translation.xy();
+
+        if position.is_far(center, config.radius) || is_covered(center, position, statics) {
+            continue;
+        }
+
+        let distance = center.distance(position);
+        let momentum = config.get_momentum(distance);
+        hits.add(
+            entity,
+            Vec2::from_length(momentum, center.angle_to(position)),
+            0.0,
+            HitZone::Torso,
+            Attack {
+                attacker: explosive.thrower,
+                weapon: None,
+                source: HitSource::Explosion(Arc::clone(config)),
+            },
+        );
+
+        if let Ok(mut effects) = effects.get_mut(entity) {
+            let share = 1.0 - distance / config.radius;
+
+            effects.add(
+                StatusEffect::Slowed,
+                CONCUSSION_SLOWDOWN * share,
+                CONCUSSION_DURATION,
+                time,
+            );
+
+            if config.burning > 0.0 {
+                effects.add(
+                    StatusEffect::Burning,
+                    config.burning * share,
+                    BURNING_DURATION,
+                    time,
+                );
+            }
+        }
+    }
+
+    for i in 0..config.fragments {
+        let direction = TAU * f32::from(i) / f32::from(config.fragments) + rng.gen_range(0.0..0.1);
+        let velocity = config.fragment_velocity * rng.gen_range(FRAGMENT_VELOCITY_MIN..1.0);
+
+        commands.add(ProjectileSpawn {
+            config: Arc::clone(&config.fragment),
+            transform: TransformLite::new(center.x, center.y, direction),
+            velocity,
+            shooter: explosive.thrower,
+            weapon: None,
+        });
+    }
+
+    commands.add(ScorchSpawn {
+        position: center,
+        size: config.radius * SCORCH_SCALE,
+    });
+}
+
+/// Whether a static obstacle shields the target from the blast
+fn is_covered(
+    center: Vec2,
+    target: Vec2,
+    statics: &Query<(&Obstacle, &Transform), Without<Explosive>>,
+) -> bool {
+    let segment = (center, target);
+
+    return statics
+        .iter()
+        .any(|(o, t)| o.intersect(t.translation.xy(), &segment).is_some());
+}
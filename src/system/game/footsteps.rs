@@ -1,26 +1,32 @@
 use crate::{
-    component::Footsteps, model::AudioPlay, resource::AudioTracker, util::math::interpolate,
+    component::{Actor, Footsteps},
+    model::{ActorActionsExt, AudioPlay, Noise},
+    resource::{AudioTracker, Noises},
+    util::math::interpolate,
 };
 use bevy::{
     ecs::system::Query,
     math::Vec3Swizzles,
-    prelude::{Res, Time, Transform},
+    prelude::{Entity, Res, ResMut, Time, Transform},
 };
 use std::time::Duration;
 
 const STRIDE_DISTANCE_MIN: f32 = 0.1;
 const STRIDE_RATE_MIN: (f32, f32, f32) = (0.1, 70.0, 0.04);
 const STRIDE_RATE_MAX: (f32, f32, f32) = (5.0, 135.0, 0.19);
+/// Distance a stride can be heard from, at min and max rate
+const STRIDE_NOISE: (f32, f32) = (2.0, 10.0);
 
 // TODO: play sound on turn
 pub fn footsteps(
-    mut query: Query<(&mut Footsteps, &Transform)>,
+    mut query: Query<(Entity, &mut Footsteps, &Transform, Option<&Actor>)>,
+    mut noises: ResMut<Noises>,
     audio: Res<AudioTracker>,
     time: Res<Time>,
 ) {
     let time = time.elapsed();
 
-    for (mut footsteps, transform) in query.iter_mut() {
+    for (entity, mut footsteps, transform, actor) in query.iter_mut() {
         let translation = transform.translation.xy();
         let distance_squared = footsteps.position.distance_squared(translation);
 
@@ -37,13 +43,26 @@ pub fn footsteps(
             continue;
         }
 
+        let quietness = if actor.map_or(false, |a| a.actions.is_crouching()) {
+            Actor::CROUCH_NOISE_FACTOR
+        } else {
+            1.0
+        };
+
         audio.queue(AudioPlay {
             path: "sounds/footstep".into(),
-            volume: calc_stride_volume(intensity),
+            volume: calc_stride_volume(intensity) * quietness,
             source: Some(translation),
             ..AudioPlay::DEFAULT
         });
 
+        noises.add(Noise {
+            position: translation,
+            loudness: interpolate(STRIDE_NOISE.0, STRIDE_NOISE.1, intensity) * quietness,
+            emitter: Some(entity),
+            time,
+        });
+
         footsteps.time = time;
         footsteps.position = translation;
     }
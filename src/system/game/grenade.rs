@@ -0,0 +1,69 @@
+use crate::{
+    command::{ExplosiveSpawn, Notify},
+    component::{Actor, Grenades, Player, Weapon, WeaponInventory},
+    model::{ActorActionsExt, AudioPlay, TransformLite},
+    resource::AudioTracker,
+    util::ext::Vec2Ext,
+};
+use bevy::{
+    ecs::{query::Has, system::Query},
+    math::Vec2,
+    prelude::{Commands, Entity, Res, Time, Transform},
+};
+
+const HAND_DISTANCE: f32 = 0.4;
+
+pub fn grenade(
+    mut query: Query<(
+        Entity,
+        &Actor,
+        &Transform,
+        &mut Grenades,
+        Option<&Weapon>,
+        Option<&WeaponInventory>,
+        Has<Player>,
+    )>,
+    mut commands: Commands,
+    audio: Res<AudioTracker>,
+    time: Res<Time>,
+) {
+    let now = time.elapsed();
+
+    for (entity, actor, transform, mut grenades, weapon, inventory, is_player) in query.iter_mut() {
+        if !actor.actions.is_throwing()
+            || weapon.map_or(false, Weapon::is_reloading)
+            || inventory.map_or(false, WeaponInventory::is_switching)
+        {
+            continue;
+        }
+
+        let Some(config) = grenades.take(now) else {
+            continue;
+        };
+
+        if is_player && grenades.count == 0 {
+            commands.add(Notify {
+                text_small: format!("No {} left", config.name).into(),
+                ..Default::default()
+            });
+        }
+
+        let transform = TransformLite::from(transform);
+        let velocity = config.get_throw_velocity(grenades.target_distance);
+
+        commands.add(ExplosiveSpawn {
+            config,
+            position: transform.translation + Vec2::from_length(HAND_DISTANCE, transform.direction),
+            direction: transform.direction,
+            velocity,
+            thrower: Some(entity),
+        });
+
+        audio.queue(AudioPlay {
+            path: "sounds/throw".into(),
+            volume: 0.6,
+            source: Some(transform.translation),
+            ..AudioPlay::DEFAULT
+        });
+    }
+}
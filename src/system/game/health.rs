@@ -22,7 +22,7 @@ pub fn health(
     config: Res<Config>,
 ) {
     for (entity, actor, mut health, transform, is_player) in query.iter_mut() {
-        let actor = actor.config;
+        let actor = &actor.config;
         let point = transform.translation.xy();
         let damage = health.get_damage_normalized();
 
@@ -52,6 +52,7 @@ pub fn health(
                     kind: actor.kind,
                     position: point,
                     is_player,
+                    attack: health.get_last_attack().cloned(),
                 });
 
                 commands.entity(entity).despawn_recursive();
@@ -1,26 +1,137 @@
 use crate::{
-    component::{Health, Inertia, Player},
-    resource::HitResource,
+    command::Notify,
+    component::{Actor, Armor, Health, Inertia, Player, StatusEffect, StatusEffects},
+    event::ActorHitEvent,
+    model::HitZone,
+    resource::{Config, Factions, HitResource},
 };
 use bevy::{
     ecs::{
         schedule::{IntoSystemConfigs, SystemConfigs},
         system::Res,
     },
-    prelude::{Query, ResMut},
+    prelude::{Commands, EventWriter, Query, ResMut},
+    time::Time,
 };
+use std::time::Duration;
+
+/// Health per second lost for each unit of damage taken
+const BLEEDING_RATE: f32 = 0.03;
+const BLEEDING_DURATION: Duration = Duration::from_secs(8);
+/// A hit stuns when its damage exceeds the pain threshold this many times
+const STUN_PAIN_FACTOR: f32 = 10.0;
+const STUN_DURATION: Duration = Duration::from_millis(600);
 
 pub fn hit_inner(
-    mut targets: Query<(&mut Inertia, &mut Health, Option<&mut Player>)>,
+    mut targets: Query<(
+        &mut Inertia,
+        &mut Health,
+        &mut Actor,
+        Option<&mut Armor>,
+        Option<&mut StatusEffects>,
+        Option<&mut Player>,
+    )>,
     mut hits: ResMut<HitResource>,
+    mut hit_events: EventWriter<ActorHitEvent>,
+    time: Res<Time>,
+    config: Res<Config>,
+    mut factions: ResMut<Factions>,
+    mut commands: Commands,
 ) {
+    let now = time.elapsed();
+
     for hit in hits.hits.drain(..) {
-        if let Ok((mut inertia, mut health, mut player)) = targets.get_mut(hit.entity) {
+        let sides = hit
+            .attack
+            .attacker
+            .filter(|e| *e != hit.entity)
+            .and_then(|e| targets.get(e).ok())
+            .zip(targets.get(hit.entity).ok())
+            .map(|(a, t)| (a.2.faction, t.2.faction));
+
+        let is_friendly = sides.map_or(false, |(a, t)| factions.is_allied(a, t));
+
+        if let Some((attacker, target)) = sides {
+            if factions.provoke(attacker, target) {
+                commands.add(Notify {
+                    text: format!("{} turned hostile", target.name()).into(),
+                    ..Default::default()
+                });
+            }
+        }
+
+        if let Ok((mut inertia, mut health, mut actor, armor, effects, mut player)) =
+            targets.get_mut(hit.entity)
+        {
             let momentum_linear = hit.momentum.length();
             let momentum_angular = momentum_linear * hit.angle;
+            let mut momentum_body = momentum_linear;
+
+            if let Some(mut armor) = armor {
+                let was_broken = armor.is_broken();
+
+                momentum_body = armor.absorb(
+                    hit.zone,
+                    momentum_linear,
+                    hit.attack.source.get_projectile(),
+                );
+
+                if !was_broken && armor.is_broken() && player.is_some() {
+                    commands.add(Notify {
+                        text_small: format!("{} is broken", armor.config.name).into(),
+                        ..Default::default()
+                    });
+                }
+            }
+
+            let mut damage = momentum_body * hit.zone.damage_factor();
+
+            if is_friendly {
+                damage *= config.game.friendly_fire.get_damage_factor();
+            }
 
             inertia.push(hit.momentum, momentum_angular, false, true);
-            health.damage(momentum_linear);
+
+            let is_lethal_headshot = hit.zone == HitZone::Head
+                && actor
+                    .config
+                    .headshot_threshold
+                    .map_or(false, |t| momentum_body >= t);
+
+            let was_alive = health.is_alive();
+
+            if is_lethal_headshot {
+                health.kill();
+            } else {
+                health.damage(damage);
+            }
+
+            hit_events.send(ActorHitEvent {
+                entity: hit.entity,
+                attack: hit.attack.clone(),
+                zone: hit.zone,
+                damage,
+                is_killing_blow: was_alive && !health.is_alive(),
+            });
+
+            if was_alive {
+                health.set_last_attack(hit.attack);
+            }
+
+            actor.wound(hit.zone, damage);
+
+            if let Some(mut effects) = effects {
+                effects.add(
+                    StatusEffect::Bleeding,
+                    damage * BLEEDING_RATE,
+                    BLEEDING_DURATION,
+                    now,
+                );
+
+                if damage / actor.config.health > actor.config.pain_threshold * STUN_PAIN_FACTOR {
+                    effects.add(StatusEffect::Stun, 1.0, STUN_DURATION, now);
+                }
+            }
 
             if let Some(player) = player.as_mut() {
                 player.shake(momentum_angular * Inertia::PUSH_MULTIPLIER_ANGULAR);
@@ -0,0 +1,100 @@
+use crate::{
+    component::{Collision, LaserSight, Obstacle},
+    model::{geometry::LineSegment, TransformLite},
+    util::ext::Vec2Ext,
+};
+use bevy::{
+    ecs::system::Query,
+    math::{Vec2, Vec3Swizzles},
+    prelude::{Entity, Parent, Transform, With, Without},
+};
+use std::collections::HashMap;
+
+/// Side of a square actors are bucketed by. Much wider than an actor
+const BUCKET_SIZE: f32 = 4.0;
+
+/// Clips laser sight beams at the first actor or obstacle in the way
+pub fn laser_sight(
+    mut sights: Query<(&Parent, &mut Transform), With<LaserSight>>,
+    actors: Query<(Entity, &Collision, &Transform), Without<LaserSight>>,
+    statics: Query<(&Obstacle, &Transform), Without<LaserSight>>,
+) {
+    if sights.is_empty() {
+        return;
+    }
+
+    // bucket actors once, so each beam checks only those along it
+    let mut buckets: HashMap<(i32, i32), Vec<(Entity, Vec2, f32)>> = HashMap::new();
+
+    for (entity, collision, transform) in actors.iter() {
+        let position = transform.translation.xy();
+        buckets
+            .entry(to_bucket(position))
+            .or_default()
+            .push((entity, position, collision.radius));
+    }
+
+    for (parent, mut transform) in sights.iter_mut() {
+        let Ok((_, _, parent_transform)) = actors.get(parent.get()) else {
+            continue;
+        };
+
+        let parent_transform = TransformLite::from(parent_transform);
+        let start = parent_transform.translation
+            + Vec2::from_length(LaserSight::OFFSET, parent_transform.direction);
+        let end = start + Vec2::from_length(LaserSight::LENGTH, parent_transform.direction);
+        let segment = (start, end);
+        let mut fraction: f32 = 1.0;
+
+        for (obstacle, obstacle_transform) in statics.iter() {
+            if let Some(contact) = obstacle.intersect(obstacle_transform.translation.xy(), &segment)
+            {
+                fraction = fraction.min(contact.fraction);
+            }
+        }
+
+        for bucket in find_buckets(&segment) {
+            for (entity, position, radius) in buckets.get(&bucket).into_iter().flatten() {
+                if *entity == parent.get() {
+                    continue;
+                }
+
+                let body = Obstacle::Circle { radius: *radius };
+
+                if let Some(contact) = body.intersect(*position, &segment) {
+                    fraction = fraction.min(contact.fraction);
+                }
+            }
+        }
+
+        *transform = LaserSight::get_transform(LaserSight::LENGTH * fraction);
+    }
+}
+
+fn to_bucket(position: Vec2) -> (i32, i32) {
+    let bucket = (position / BUCKET_SIZE).floor();
+    return (bucket.x as i32, bucket.y as i32);
+}
+
+/// Buckets which may hold actors touching the segment
+fn find_buckets(segment: &LineSegment) -> Vec<(i32, i32)> {
+    // sampled every quarter of a bucket, so bodies around the segment are in the neighbor buckets
+    let steps = (segment.0.distance(segment.1) / BUCKET_SIZE * 4.0)
+        .ceil()
+        .max(1.0) as usize;
+    let mut buckets = Vec::new();
+
+    for i in 0..=steps {
+        let (x, y) = to_bucket(segment.0.lerp(segment.1, i as f32 / steps as f32));
+
+        for dy in -1..=1 {
+            for dx in -1..=1 {
+                buckets.push((x + dx, y + dy));
+            }
+        }
+    }
+
+    buckets.sort_unstable();
+    buckets.dedup();
+    return buckets;
+}
@@ -1,48 +1,63 @@
 use crate::{
     command::ActorMeleeReset,
-    component::{Actor, ActorConfig, Weapon},
-    model::{ActorActionsExt, AudioPlay, TransformLite},
-    resource::{AudioTracker, HitResource},
+    component::{Actor, ActorConfig, Weapon, WeaponInventory},
+    model::{ActorActionsExt, Attack, AudioPlay, HitSource, HitZone, TransformLite},
+    resource::{AudioTracker, Config, Factions, HitResource},
     util::{ext::Vec2Ext, math},
 };
 use bevy::{
     ecs::entity::Entity,
     math::Vec2Swizzles,
-    prelude::{Commands, Query, Res, ResMut, Transform, Vec2, Without},
+    prelude::{Commands, Query, Res, ResMut, Transform, Vec2},
     time::Time,
 };
+use std::{sync::Arc, time::Duration};
 
 pub fn melee(
-    attackers: Query<(Entity, &Actor, &Transform), Without<Weapon>>,
+    attackers: Query<(
+        Entity,
+        &Actor,
+        &Transform,
+        Option<&Weapon>,
+        Option<&WeaponInventory>,
+    )>,
     targets: Query<(Entity, &Actor, &Transform)>,
     mut hits: ResMut<HitResource>,
     audio: Res<AudioTracker>,
     mut commands: Commands,
     time: Res<Time>,
+    config: Res<Config>,
+    factions: Res<Factions>,
 ) {
     let time = time.elapsed();
+    let friendly_fire = config.game.friendly_fire;
 
-    for (attacker_entity, attacker_actor, attacker_transform) in attackers.iter() {
-        if !attacker_actor.actions.is_attacking() {
+    for (attacker_entity, attacker_actor, attacker_transform, weapon, inventory) in attackers.iter()
+    {
+        if attacker_actor.melee_next > time
+            || inventory.map_or(false, WeaponInventory::is_switching)
+        {
             continue;
         }
 
-        if attacker_actor.melee_next > time {
+        let Some(strike) = Strike::new(attacker_actor, weapon) else {
             continue;
-        }
+        };
 
         let attacker_transform = TransformLite::from(attacker_transform);
         let mut victim: Option<TargetData> = None;
 
         for (target_entity, target_actor, target_transform) in targets.iter() {
-            if attacker_actor.config.kind == target_actor.config.kind {
+            if attacker_entity == target_entity
+                || friendly_fire.spares(&factions, Some(attacker_actor), target_actor)
+            {
                 continue;
             }
 
             if let Some(target_data) = calc_target_data(
-                attacker_actor.config,
+                &strike,
                 &attacker_transform,
-                target_actor.config,
+                &target_actor.config,
                 &TransformLite::from(target_transform),
                 target_entity,
             ) {
@@ -56,9 +71,19 @@ pub fn melee(
         }
 
         if let Some(victim) = victim {
-            let momentum = attacker_actor.config.melee_damage * attacker_actor.skill;
+            let momentum = strike.damage * attacker_actor.skill;
             let force = Vec2::from_length(momentum, victim.angle_objective);
-            hits.add(victim.entity, force, -victim.angle_subjective);
+            hits.add(
+                victim.entity,
+                force,
+                -victim.angle_subjective,
+                victim.zone,
+                Attack {
+                    attacker: Some(attacker_entity),
+                    weapon: weapon.map(|w| Arc::clone(&w.config)),
+                    source: HitSource::Melee,
+                },
+            );
 
             audio.queue(AudioPlay {
                 path: "sounds/melee".into(),
@@ -67,27 +92,89 @@ pub fn melee(
                 ..AudioPlay::DEFAULT
             });
 
-            commands.add(ActorMeleeReset(attacker_entity));
+            commands.add(ActorMeleeReset {
+                entity: attacker_entity,
+                interval: strike.interval,
+            });
         }
     }
 }
 
+/// A melee attack with fists, a melee weapon or a firearm stock
+struct Strike {
+    damage: f32,
+    /// Distance from the attacker body
+    reach: f32,
+    arc: f32,
+    interval: Duration,
+}
+
+impl Strike {
+    /// Finds which strike the actor attempts now, if any
+    fn new(actor: &Actor, weapon: Option<&Weapon>) -> Option<Self> {
+        let is_attacking = actor.actions.is_attacking();
+        let is_bashing = actor.actions.is_bashing();
+
+        let Some(weapon) = weapon.map(|w| &w.config) else {
+            if !is_attacking && !is_bashing {
+                return None;
+            }
+
+            return Some(Self::from_actor(&actor.config));
+        };
+
+        if let Some(melee) = &weapon.melee {
+            if !is_attacking && !is_bashing {
+                return None;
+            }
+
+            return Some(Self {
+                damage: melee.damage,
+                reach: melee.reach,
+                arc: melee.arc,
+                interval: melee.swing_time,
+            });
+        }
+
+        // firearms shoot on attack, so only bash
+        if !is_bashing {
+            return None;
+        }
+
+        return Some(Self {
+            damage: weapon.get_bash_damage(),
+            interval: weapon.get_bash_interval(),
+            ..Self::from_actor(&actor.config)
+        });
+    }
+
+    fn from_actor(actor: &ActorConfig) -> Self {
+        return Self {
+            damage: actor.melee_damage,
+            reach: actor.melee_distance,
+            arc: actor.melee_distance_angular,
+            interval: actor.melee_interval,
+        };
+    }
+}
+
 struct TargetData {
     entity: Entity,
     distance: f32,
     angle_objective: f32,
     angle_subjective: f32,
+    zone: HitZone,
 }
 
 fn calc_target_data(
-    attacker: &ActorConfig,
+    strike: &Strike,
     attacker_transform: &TransformLite,
     target: &ActorConfig,
     target_transform: &TransformLite,
     target_entity: Entity,
 ) -> Option<TargetData> {
     let relative = target_transform.translation - attacker_transform.translation;
-    let distance_to_hit = attacker.melee_distance + target.radius;
+    let distance_to_hit = strike.reach + target.radius;
 
     if relative.is_long(distance_to_hit) {
         return None;
@@ -95,7 +182,7 @@ fn calc_target_data(
 
     let angle_objective = relative.angle();
     let angle_subjective = math::angle_difference(angle_objective, attacker_transform.direction);
-    let distance_angular = angle_subjective.abs() / (attacker.melee_distance_angular / 2.0);
+    let distance_angular = angle_subjective.abs() / (strike.arc / 2.0);
 
     if distance_angular > 1.0 {
         return None;
@@ -103,10 +190,15 @@ fn calc_target_data(
 
     let distance = relative.length() / distance_to_hit;
 
+    // the point of the swing line closest to the target center
+    let facing = Vec2::from_length(1.0, attacker_transform.direction);
+    let contact = attacker_transform.translation + facing * relative.dot(facing);
+
     return Some(TargetData {
         entity: target_entity,
         distance: distance * distance_angular,
         angle_objective,
         angle_subjective,
+        zone: HitZone::find(contact, target_transform, target.radius),
     });
 }
@@ -1,29 +1,37 @@
 mod actor;
 mod ambience_fx;
+mod beam;
 mod blood;
 mod bonus;
 mod breath;
 mod camera;
 mod collision_find;
 mod collision_resolve;
+mod consumable_inventory;
+mod explosive;
 mod footsteps;
+mod grenade;
 mod health;
 mod heartbeat;
 mod hit;
 mod inertia;
 mod input;
+mod laser_sight;
 mod melee;
 mod on_enter;
 mod player;
 mod projectile;
 mod scenario;
 mod status_bar;
+mod status_effects;
 mod terrain;
 mod weapon;
+mod weapon_inventory;
 
 pub use self::{
-    actor::*, ambience_fx::*, blood::*, bonus::*, breath::*, camera::*, collision_find::*,
-    collision_resolve::*, footsteps::*, health::*, heartbeat::*, hit::*, inertia::*, input::*,
-    melee::*, on_enter::*, player::*, projectile::*, scenario::*, status_bar::*, terrain::*,
-    weapon::*,
+    actor::*, ambience_fx::*, beam::*, blood::*, bonus::*, breath::*, camera::*, collision_find::*,
+    collision_resolve::*, consumable_inventory::*, explosive::*, footsteps::*, grenade::*,
+    health::*, heartbeat::*, hit::*, inertia::*, input::*, laser_sight::*, melee::*, on_enter::*,
+    player::*, projectile::*, scenario::*, status_bar::*, status_effects::*, terrain::*, weapon::*,
+    weapon_inventory::*,
 };
@@ -1,5 +1,6 @@
 use crate::{
-    command::{CursorGrab, TerrainInit},
+    command::{CursorGrab, NavigationInit, TerrainInit},
+    component::Obstacle,
     data::{LAYER_BLUFF, LAYER_TREE, WORLD_SIZE, WORLD_SIZE_HALF, WORLD_SIZE_VISUAL},
     model::{AudioPlay, TransformLite},
     resource::AudioTracker,
@@ -8,7 +9,9 @@ use crate::{
 use bevy::{
     asset::{AssetServer, Handle},
     math::Vec2,
-    prelude::{Camera2dBundle, Commands, Image, Res, SpriteBundle},
+    prelude::{
+        Camera2dBundle, Commands, Entity, Image, Res, SpriteBundle, Transform, TransformBundle,
+    },
 };
 use rand::{seq::SliceRandom, Rng, SeedableRng};
 use rand_pcg::Pcg32;
@@ -21,6 +24,7 @@ const TREES_PER_METER: f32 = 0.02;
 const TREES_QUANTITY: f32 = WORLD_SIZE_VISUAL * WORLD_SIZE_VISUAL * TREES_PER_METER;
 const TREE_BUFFER_ZONE: f32 = 3.2;
 const TREE_FIND_POSITION_ATTEMPTS: usize = 32;
+const TREE_TRUNK_RADIUS: f32 = 0.35;
 const BLUFF_SPRITE_SIZE: f32 = 4.0;
 
 pub fn on_enter(mut commands: Commands, assets: Res<AssetServer>, audio: Res<AudioTracker>) {
@@ -29,6 +33,7 @@ pub fn on_enter(mut commands: Commands, assets: Res<AssetServer>, audio: Res<Aud
     commands.spawn(Camera2dBundle::default());
     spawn_bluffs(&mut commands, &assets);
     spawn_trees(&mut commands, &assets);
+    commands.add(NavigationInit);
 
     audio.queue(AudioPlay {
         path: "sounds/ambience_music".into(),
@@ -79,6 +84,22 @@ fn spawn_bluffs(commands: &mut Commands, assets: &AssetServer) {
     spawn_sprite(commands, n, n, z, r2, texture_corner.clone());
     spawn_sprite(commands, -n, n, z, r3, texture_corner.clone());
     spawn_sprite(commands, n, -n, z, r4, texture_corner);
+
+    // colliders cover the outer half of bluff sprites, beyond the world edge
+    let depth = BLUFF_SPRITE_SIZE / 4.0;
+    let length = n + BLUFF_SPRITE_SIZE / 2.0;
+
+    for (position, half_size) in [
+        (Vec2::new(0.0, -n - depth), Vec2::new(length, depth)),
+        (Vec2::new(0.0, n + depth), Vec2::new(length, depth)),
+        (Vec2::new(-n - depth, 0.0), Vec2::new(depth, length)),
+        (Vec2::new(n + depth, 0.0), Vec2::new(depth, length)),
+    ] {
+        commands.spawn((
+            TransformBundle::from_transform(Transform::from_translation(position.extend(z))),
+            Obstacle::Box { half_size },
+        ));
+    }
 }
 
 fn spawn_trees(commands: &mut Commands, assets: &AssetServer) {
@@ -101,7 +122,7 @@ fn spawn_trees(commands: &mut Commands, assets: &AssetServer) {
             if is_position_free(position, &occupied_positions) {
                 let texture = textures.choose(&mut rng).unwrap_or(&textures[0]).clone();
 
-                spawn_sprite(
+                let tree = spawn_sprite(
                     commands,
                     position.x,
                     position.y,
@@ -110,6 +131,10 @@ fn spawn_trees(commands: &mut Commands, assets: &AssetServer) {
                     texture,
                 );
 
+                commands.entity(tree).insert(Obstacle::Circle {
+                    radius: TREE_TRUNK_RADIUS,
+                });
+
                 occupied_positions.push(position);
                 break;
             }
@@ -124,12 +149,14 @@ fn spawn_sprite(
     z: f32,
     direction: f32,
     texture: Handle<Image>,
-) {
-    commands.spawn(SpriteBundle {
-        transform: TransformLite::new(x, y, direction).as_transform(z),
-        texture,
-        ..Default::default()
-    });
+) -> Entity {
+    return commands
+        .spawn(SpriteBundle {
+            transform: TransformLite::new(x, y, direction).as_transform(z),
+            texture,
+            ..Default::default()
+        })
+        .id();
 }
 
 fn is_position_free(position: Vec2, occupied_positions: &[Vec2]) -> bool {
@@ -1,6 +1,8 @@
 use crate::{
-    component::{Actor, Player},
-    data::WORLD_SIZE_HALF,
+    command::Notify,
+    component::{
+        Actor, Consumable, ConsumableInventory, Player, Weapon, WeaponAttachment, WeaponInventory,
+    },
     model::{ActorAction, ActorActionsExt},
     resource::Config,
 };
@@ -8,36 +10,50 @@ use bevy::{
     ecs::system::Query,
     input::mouse::{MouseMotion, MouseWheel},
     math::Vec2,
-    prelude::{EventReader, Input, KeyCode, MouseButton, Res, Transform},
+    prelude::{Commands, EventReader, Input, KeyCode, MouseButton, Res, Transform},
     time::Time,
 };
 
+const WEAPON_SLOT_KEYS: [KeyCode; WeaponInventory::SLOTS] = [KeyCode::Key1, KeyCode::Key2];
+const CONSUMABLE_KEYS: [KeyCode; Consumable::ALL.len()] =
+    [KeyCode::Key3, KeyCode::Key4, KeyCode::Key5, KeyCode::Key6];
+
 pub fn player(
-    mut query: Query<(&mut Player, &mut Actor, &mut Transform)>,
+    mut query: Query<(
+        &mut Player,
+        &mut Actor,
+        &mut Transform,
+        Option<&mut Weapon>,
+        Option<&mut WeaponInventory>,
+        Option<&mut ConsumableInventory>,
+    )>,
     keyboard: Res<Input<KeyCode>>,
     mouse: Res<Input<MouseButton>>,
     mut mouse_motion: EventReader<MouseMotion>,
     mut mouse_scroll: EventReader<MouseWheel>,
+    mut commands: Commands,
     time: Res<Time>,
     config: Res<Config>,
 ) {
     let delta = time.delta_seconds();
 
     let mut mouse_delta_x = 0.0;
-    let mut zoom = 0.0;
+    let mut scroll = 0.0;
 
     for event in mouse_motion.read() {
         mouse_delta_x -= event.delta.x;
     }
 
     for event in mouse_scroll.read() {
-        zoom += event.y;
+        scroll += event.y;
     }
 
+    let is_scroll_switching = keyboard.pressed(KeyCode::ControlLeft);
+
     let rotation = mouse_delta_x * config.controls.mouse_sensitivity;
     let extra_rotation = rotation * Player::EXTRA_ROTATION_MULTIPLAYER;
 
-    for (mut player, mut actor, mut transform) in query.iter_mut() {
+    for (mut player, mut actor, mut transform, weapon, inventory, consumables) in query.iter_mut() {
         if !player.is_controllable {
             continue;
         }
@@ -72,12 +88,67 @@ pub fn player(
             .actions
             .set(ActorAction::Reload, keyboard.pressed(KeyCode::R));
 
-        player.add_zoom(zoom);
+        actor
+            .actions
+            .set(ActorAction::Throw, keyboard.pressed(KeyCode::Q));
+
+        actor
+            .actions
+            .set(ActorAction::Bash, keyboard.pressed(KeyCode::V));
+
+        actor
+            .actions
+            .set(ActorAction::Crouch, keyboard.pressed(KeyCode::C));
+
+        actor.actions.set(
+            ActorAction::Aim,
+            weapon.is_some() && mouse.pressed(MouseButton::Right),
+        );
+
+        player.set_aiming(actor.actions.is_aiming());
+
+        player.set_scope(
+            weapon
+                .as_ref()
+                .map_or(false, |w| w.has_attachment(WeaponAttachment::Scope)),
+        );
+
+        if let Some(mut weapon) = weapon {
+            if keyboard.just_pressed(KeyCode::B) && weapon.config.fire_modes.len() > 1 {
+                let fire_mode = weapon.cycle_fire_mode();
+
+                commands.add(Notify {
+                    text_small: format!("Fire mode: {}", fire_mode.name()).into(),
+                    ..Default::default()
+                });
+            }
+        }
+
+        if let Some(mut inventory) = inventory {
+            for (slot, key) in WEAPON_SLOT_KEYS.iter().enumerate() {
+                if keyboard.just_pressed(*key) {
+                    inventory.request(slot);
+                }
+            }
+
+            if is_scroll_switching && scroll != 0.0 {
+                inventory.request_next(scroll < 0.0);
+            }
+        }
+
+        if let Some(mut consumables) = consumables {
+            for (item, key) in Consumable::ALL.iter().zip(CONSUMABLE_KEYS.iter()) {
+                if keyboard.just_pressed(*key) {
+                    consumables.request(*item);
+                }
+            }
+        }
+
+        if !is_scroll_switching {
+            player.add_zoom(scroll);
+        }
+
         player.update(delta);
         transform.rotate_local_z(rotation + player.add_extra_rotation(extra_rotation));
-
-        let limit = WORLD_SIZE_HALF;
-        transform.translation.x = transform.translation.x.clamp(-limit, limit);
-        transform.translation.y = transform.translation.y.clamp(-limit, limit);
     }
 }
@@ -1,7 +1,11 @@
 use crate::{
-    component::{Actor, Collision, Projectile},
-    model::{geometry::GeometryProjection, AudioPlay},
-    resource::{AudioTracker, HitResource},
+    command::ImpactSpawn,
+    component::{Actor, Collision, Obstacle, ObstacleContact, Projectile},
+    model::{
+        geometry::{GeometryProjection, LineSegment},
+        Attack, AudioPlay, HitSource, HitZone, TransformLite,
+    },
+    resource::{AudioTracker, Config, Factions, FriendlyFire, HitResource},
     util::{ext::Vec2Ext, math},
 };
 use bevy::{
@@ -9,17 +13,23 @@ use bevy::{
     math::{Quat, Vec3Swizzles},
     prelude::{Commands, DespawnRecursiveExt, Res, ResMut, Time, Transform, Vec2, Without},
 };
-use std::time::Duration;
+use std::{sync::Arc, time::Duration};
 
 const TIME_DELTA_FOR_RENDER: Duration = Duration::from_millis(25); // 40 FPS
+const RICOCHET_ANGLE_MAX: f32 = 0.26; // 15 degrees
+const RICOCHET_VELOCITY_FACTOR: f32 = 0.6;
+const RICOCHET_OFFSET: f32 = 0.01;
 
 pub fn projectile(
     mut projectiles: Query<(Entity, &mut Projectile, &mut Transform)>,
     obstacles: Query<(Entity, &Collision, &Transform, &Actor), Without<Projectile>>,
+    statics: Query<(&Obstacle, &Transform), Without<Projectile>>,
     mut hits: ResMut<HitResource>,
     mut commands: Commands,
     audio: Res<AudioTracker>,
     time: Res<Time>,
+    config: Res<Config>,
+    factions: Res<Factions>,
 ) {
     let t0 = time.elapsed();
     let t1 = t0.saturating_sub(time.delta());
@@ -35,9 +45,40 @@ pub fn projectile(
         let (tail, tail_velocity) = projectile.calc_data(t1);
         let tail_visual = projectile.calc_data(t2).0;
 
-        if let Some((obstacle, obstacle_position, contact_position, _)) =
-            find_obstacle(&(head, tail), projectile.shooter, &obstacles)
-        {
+        let segment = (tail, head);
+        let mut obstacle = find_obstacle(
+            &segment,
+            &projectile,
+            &obstacles,
+            config.game.friendly_fire,
+            &factions,
+        );
+
+        let contact = find_static_obstacle(&segment, &statics).filter(|c| {
+            // a static obstacle is closer than an actor
+            obstacle.map_or(true, |o| {
+                o.2.distance_squared(tail) > c.position.distance_squared(tail)
+            })
+        });
+
+        if let Some(contact) = contact {
+            let contact_velocity =
+                find_contact_velocity(contact.position, head, tail, head_velocity, tail_velocity);
+
+            hit_static_obstacle(
+                &mut projectile,
+                &contact,
+                contact_velocity,
+                t0,
+                &audio,
+                &mut commands,
+            );
+
+            head = contact.position;
+            obstacle = None;
+        }
+
+        if let Some((obstacle, obstacle_position, contact_position, _)) = obstacle {
             let contact_velocity =
                 find_contact_velocity(contact_position, head, tail, head_velocity, tail_velocity);
 
@@ -51,14 +92,40 @@ pub fn projectile(
                 ..AudioPlay::DEFAULT
             });
 
+            let zone = obstacles.get(obstacle).map_or(HitZone::Torso, |o| {
+                HitZone::find(contact_position, &TransformLite::from(o.2), o.1.radius)
+            });
+
+            let velocity =
+                contact_velocity * projectile.config.penetrate_body(contact_velocity.length());
+            let is_stuck = has_stopped(velocity);
+
+            // the body takes only the momentum the fragment loses in it
+            let velocity_lost = if is_stuck {
+                contact_velocity
+            } else {
+                contact_velocity - velocity
+            };
+
             hits.add(
                 obstacle,
-                contact_velocity * projectile.config.fragment_mass(),
+                velocity_lost * projectile.config.fragment_mass(),
                 angle,
+                zone,
+                Attack {
+                    attacker: projectile.shooter,
+                    weapon: projectile.weapon.clone(),
+                    source: HitSource::Projectile(Arc::clone(&projectile.config)),
+                },
             );
 
             head = contact_position;
-            projectile.stopped = true;
+
+            if is_stuck {
+                projectile.stopped = true;
+            } else {
+                projectile.penetrate(obstacle, t0, contact_position, velocity);
+            }
         }
 
         update_transform(&projectile, head, tail_visual, &mut transform);
@@ -69,27 +136,96 @@ pub fn projectile(
     }
 }
 
+fn find_static_obstacle(
+    segment: &LineSegment,
+    statics: &Query<(&Obstacle, &Transform), Without<Projectile>>,
+) -> Option<ObstacleContact> {
+    let mut result: Option<ObstacleContact> = None;
+
+    for (obstacle, transform) in statics.iter() {
+        if let Some(contact) = obstacle.intersect(transform.translation.xy(), segment) {
+            if result
+                .as_ref()
+                .map_or(true, |r| r.fraction > contact.fraction)
+            {
+                result = Some(contact);
+            }
+        }
+    }
+
+    return result;
+}
+
+/// Stops the projectile, or ricochets it at shallow angles
+fn hit_static_obstacle(
+    projectile: &mut Projectile,
+    contact: &ObstacleContact,
+    velocity: Vec2,
+    time: Duration,
+    audio: &AudioTracker,
+    commands: &mut Commands,
+) {
+    let direction = velocity.normalize_or_zero();
+    let incidence = f32::asin(-direction.dot(contact.normal).clamp(-1.0, 1.0));
+    let velocity =
+        (velocity - 2.0 * velocity.dot(contact.normal) * contact.normal) * RICOCHET_VELOCITY_FACTOR;
+
+    if incidence < RICOCHET_ANGLE_MAX && !has_stopped(velocity) {
+        projectile.redirect(
+            time,
+            contact.position + contact.normal * RICOCHET_OFFSET,
+            velocity,
+        );
+
+        audio.queue(AudioPlay {
+            path: "sounds/ricochet".into(),
+            volume: 0.8,
+            source: Some(contact.position),
+            ..AudioPlay::DEFAULT
+        });
+    } else {
+        projectile.stopped = true;
+        commands.add(ImpactSpawn {
+            position: contact.position,
+        });
+
+        audio.queue(AudioPlay {
+            path: "sounds/hit_obstacle".into(),
+            volume: 0.8,
+            source: Some(contact.position),
+            ..AudioPlay::DEFAULT
+        });
+    }
+}
+
 fn find_obstacle(
-    projectile: &(Vec2, Vec2),
-    shooter: Option<Entity>,
+    segment: &(Vec2, Vec2),
+    projectile: &Projectile,
     obstacles: &Query<(Entity, &Collision, &Transform, &Actor), Without<Projectile>>,
+    friendly_fire: FriendlyFire,
+    factions: &Factions,
 ) -> Option<(Entity, Vec2, Vec2, f32)> {
     let mut result: Option<(Entity, Vec2, Vec2, f32)> = None;
 
-    let shooter_kind = shooter
-        .and_then(|e| obstacles.get(e).ok())
-        .map(|q| q.3.config.kind);
+    let shooter = projectile.shooter;
+    let shooter_actor = shooter.and_then(|e| obstacles.get(e).ok()).map(|q| q.3);
 
     for (entity, collision, transform, actor) in obstacles.iter() {
-        if shooter == Some(entity) || shooter_kind == Some(actor.config.kind) {
+        let is_shooter = shooter == Some(entity);
+
+        // fired rounds start inside the shooter, but fragments can hit their own thrower
+        if (is_shooter && projectile.weapon.is_some())
+            || projectile.penetrated == Some(entity)
+            || (!is_shooter && friendly_fire.spares(factions, shooter_actor, actor))
+        {
             continue;
         }
 
         let obstacle = transform.translation.xy();
-        let contact = obstacle.project_on(projectile);
+        let contact = obstacle.project_on(segment);
 
         if obstacle.is_close(contact, collision.radius) {
-            let tail_distance = obstacle.distance_squared(projectile.1);
+            let tail_distance = obstacle.distance_squared(segment.0);
 
             if result.map_or(true, |o| o.3 > tail_distance) {
                 result = Some((entity, obstacle, contact, tail_distance));
@@ -125,3 +261,86 @@ fn update_transform(projectile: &Projectile, head: Vec2, tail: Vec2, transform:
 fn has_stopped(velocity: Vec2) -> bool {
     return velocity.is_short(Projectile::VELOCITY_MIN);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        component::ProjectileConfig,
+        data::{load_actors, ACTORS_PATH},
+    };
+    use bevy::{ecs::system::SystemState, prelude::World};
+
+    #[test]
+    fn test_find_obstacle_nearest_to_tail() {
+        let actors = load_actors(ACTORS_PATH).expect("Failed to load actors");
+        let human = actors.get("human").expect("No human actor");
+        let mut world = World::new();
+
+        let spawn = |world: &mut World, x: f32| {
+            return world
+                .spawn((
+                    Collision { radius: 0.25 },
+                    Transform::from_xyz(x, 0.0, 0.0),
+                    Actor::new(Arc::clone(human), 1.0),
+                ))
+                .id();
+        };
+
+        // the farther one is spawned first, so iteration order doesn't pick the right one by luck
+        spawn(&mut world, 2.0);
+        let near = spawn(&mut world, 1.0);
+
+        let projectile = Projectile::new(
+            Arc::new(ProjectileConfig {
+                caliber: "9x18".to_owned(),
+                fragments: 1,
+                mass: 6.1,
+                size: 1.0,
+                penetration: 0.0,
+                armor_piercing: 0.0,
+            }),
+            Duration::ZERO,
+            Vec2::ZERO,
+            Vec2::new(300.0, 0.0),
+            None,
+            None,
+        );
+
+        let mut state: SystemState<
+            Query<(Entity, &Collision, &Transform, &Actor), Without<Projectile>>,
+        > = SystemState::new(&mut world);
+
+        let obstacles = state.get(&world);
+        let segment = (Vec2::ZERO, Vec2::new(3.0, 0.0));
+        let found = find_obstacle(
+            &segment,
+            &projectile,
+            &obstacles,
+            FriendlyFire::Full,
+            &Factions::default(),
+        );
+
+        assert_eq!(found.map(|o| o.0), Some(near));
+
+        // a fragment of the near one's grenade
+        let fragment = Projectile::new(
+            Arc::clone(&projectile.config),
+            Duration::ZERO,
+            Vec2::ZERO,
+            Vec2::new(300.0, 0.0),
+            Some(near),
+            None,
+        );
+
+        let found = find_obstacle(
+            &segment,
+            &fragment,
+            &obstacles,
+            FriendlyFire::Off,
+            &Factions::default(),
+        );
+
+        assert_eq!(found.map(|o| o.0), Some(near));
+    }
+}
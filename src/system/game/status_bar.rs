@@ -1,5 +1,5 @@
 use crate::{
-    component::{Actor, Health, Weapon},
+    component::{Actor, Armor, Health, Weapon},
     StatusBarMaterial,
 };
 use bevy::{
@@ -13,7 +13,7 @@ const INTERPOLATION: f32 = 8.0;
 const PULSE: Duration = Duration::from_millis(500);
 
 pub fn status_bar(
-    targets: Query<(&Actor, &Health, Option<&Weapon>, &Children)>, // TODO: try to simplify
+    targets: Query<(&Actor, &Health, Option<&Weapon>, Option<&Armor>, &Children)>, // TODO: try to simplify
     handles: Query<&Handle<StatusBarMaterial>>,
     mut assets: ResMut<Assets<StatusBarMaterial>>,
     time: Res<Time>,
@@ -21,7 +21,7 @@ pub fn status_bar(
     let pulse = (time.elapsed_seconds() * TAU / PULSE.as_secs_f32()).cos() / 2.0 + 0.5;
     let interpolation = f32::min(INTERPOLATION * time.delta().as_secs_f32(), 1.0);
 
-    for (actor, health, weapon, children) in targets.iter() {
+    for (actor, health, weapon, armor, children) in targets.iter() {
         for child in children.iter() {
             if let Some(material) = handles.get(*child).ok().and_then(|h| assets.get_mut(h)) {
                 material.health -= (material.health - health.get_normalized()) * interpolation;
@@ -35,7 +35,7 @@ pub fn status_bar(
                 if let Some(weapon) = weapon {
                     material.ammo = weapon.get_ammo_normalized(time.elapsed());
 
-                    if weapon.is_reloading() {
+                    if weapon.is_reloading() || weapon.is_overheated(time.elapsed()) {
                         material.ammo_alpha = pulse;
                     } else {
                         material.ammo_alpha = 1.0;
@@ -45,6 +45,13 @@ pub fn status_bar(
                 }
 
                 material.stamina = actor.stamina;
+
+                if let Some(armor) = armor {
+                    material.armor = armor.get_durability_normalized();
+                    material.armor_alpha = 1.0;
+                } else {
+                    material.armor_alpha = 0.0;
+                }
             }
         }
     }
@@ -0,0 +1,27 @@
+use crate::component::{Actor, Health, StatusEffects};
+use bevy::{
+    ecs::system::{Query, Res},
+    time::Time,
+};
+
+pub fn status_effects(
+    mut query: Query<(&mut StatusEffects, &mut Health, &mut Actor)>,
+    time: Res<Time>,
+) {
+    let now = time.elapsed();
+    let delta = time.delta_seconds();
+
+    for (mut effects, mut health, mut actor) in query.iter_mut() {
+        effects.update(now);
+
+        if !health.is_alive() {
+            continue;
+        }
+
+        health.damage(effects.get_damage_rate() * delta);
+
+        if effects.is_stunned() {
+            actor.reset_actions();
+        }
+    }
+}
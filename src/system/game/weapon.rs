@@ -1,19 +1,25 @@
 use crate::{
-    command::ProjectileSpawn,
-    component::{Actor, Inertia, Player, Weapon, WeaponFireResult},
-    model::{ActorActionsExt, AudioPlay, TransformLite},
-    resource::AudioTracker,
-    util::ext::Vec2Ext,
+    command::{HitscanFire, ProjectileSpawn},
+    component::{Actor, AmmoReserve, Inertia, Player, Weapon, WeaponFireResult, WeaponInventory},
+    model::{ActorActionsExt, AudioPlay, Noise, TransformLite},
+    resource::{AudioTracker, Noises},
+    util::{ext::Vec2Ext, math::interpolate},
 };
 use bevy::{
     ecs::system::{Local, Query},
     math::{Vec2, Vec3Swizzles},
-    prelude::{Commands, Entity, Res, Time, Transform},
+    prelude::{Commands, Entity, Res, ResMut, Time, Transform},
 };
 use rand::{Rng, SeedableRng};
 use rand_pcg::Pcg32;
+use std::sync::Arc;
 
 const BARREL_LENGTH: f32 = 0.6; // TODO: don't hardcode
+const STEADINESS_EXHAUSTED: f32 = 0.5;
+/// Distances bots can hear weapons from, meters
+const SHOT_NOISE: f32 = 60.0;
+const LASER_NOISE: f32 = 15.0;
+const RELOADING_NOISE: f32 = 5.0;
 
 pub struct WeaponSystemData {
     rng: Pcg32,
@@ -35,53 +41,98 @@ pub fn weapon(
         &Transform,
         &mut Weapon,
         &mut Inertia,
+        Option<&mut AmmoReserve>,
+        Option<&WeaponInventory>,
         Option<&mut Player>,
     )>,
     mut commands: Commands,
+    mut noises: ResMut<Noises>,
     audio: Res<AudioTracker>,
     time: Res<Time>,
 ) {
     let now = time.elapsed();
+    let delta = time.delta_seconds();
 
-    for (entity, actor, transform, mut weapon, mut inertia, mut player) in query.iter_mut() {
-        if !actor.actions.is_attacking() {
+    for (entity, actor, transform, mut weapon, mut inertia, mut reserve, inventory, mut player) in
+        query.iter_mut()
+    {
+        if inventory.map_or(false, WeaponInventory::is_switching) {
             weapon.release_trigger();
+            continue;
         }
 
-        if actor.actions.is_reloading() && !weapon.is_reloading() {
-            let reloading_duration = weapon
-                .config
-                .reloading_time
-                .mul_f32(actor.config.reloading_speed)
-                .div_f32(actor.skill);
+        // melee weapons strike in the melee system
+        if weapon.config.melee.is_some() {
+            continue;
+        }
 
-            weapon.reload(now, reloading_duration);
+        // a stocked weapon held by a skilled and rested actor settles faster
+        let steadiness = weapon.config.grip.recoil_factor()
+            * actor.skill
+            * interpolate(STEADINESS_EXHAUSTED, 1.0, actor.stamina);
 
-            audio.queue(AudioPlay {
-                path: "sounds/reloading".into(),
-                volume: 0.4,
-                source: Some(transform.translation.xy()),
-                duration: reloading_duration, // TODO: stop if weapon will be changed earlier
-            });
+        weapon.recover_bloom(delta, steadiness);
 
-            continue;
+        let projectile = weapon.config.projectile.clone();
+
+        if !actor.actions.is_attacking() {
+            weapon.release_trigger();
         }
 
-        if weapon.is_reloading() && weapon.is_ready(now) {
-            let was_armed = weapon.is_armed();
-            weapon.complete_reloading(now);
+        // hitscan weapons have nothing to reload
+        if let Some(caliber) = projectile.as_ref().map(|p| p.caliber.as_str()) {
+            if actor.actions.is_reloading()
+                && !weapon.is_reloading()
+                && reserve.as_ref().map_or(true, |r| r.get(caliber) > 0)
+            {
+                let reloading_duration = weapon
+                    .config
+                    .reloading_time
+                    .mul_f32(actor.config.reloading_speed)
+                    .mul_f32(1.0 + actor.arm_wound * Actor::ARM_WOUND_RELOADING)
+                    .div_f32(actor.skill);
+
+                let unloaded = weapon.reload(now, reloading_duration);
+
+                if let Some(reserve) = reserve.as_mut() {
+                    reserve.add(caliber, u16::from(unloaded));
+                }
 
-            if !was_armed {
                 audio.queue(AudioPlay {
-                    path: "sounds/reloaded".into(),
-                    volume: 0.8,
+                    path: "sounds/reloading".into(),
+                    volume: 0.4,
                     source: Some(transform.translation.xy()),
-                    ..AudioPlay::DEFAULT
+                    duration: reloading_duration, // TODO: stop if weapon will be changed earlier
+                });
+
+                noises.add(Noise {
+                    position: transform.translation.xy(),
+                    loudness: RELOADING_NOISE,
+                    emitter: Some(entity),
+                    time: now,
                 });
+
+                continue;
+            }
+
+            if weapon.is_reloading() && weapon.is_ready(now) {
+                let was_armed = weapon.is_armed();
+                let demand = weapon.get_reloading_demand();
+                let loaded = reserve.as_mut().map_or(demand, |r| r.take(caliber, demand));
+                weapon.complete_reloading(now, loaded);
+
+                if !was_armed {
+                    audio.queue(AudioPlay {
+                        path: "sounds/reloaded".into(),
+                        volume: 0.8,
+                        source: Some(transform.translation.xy()),
+                        ..AudioPlay::DEFAULT
+                    });
+                }
             }
         }
 
-        if actor.actions.is_attacking() {
+        if actor.actions.is_attacking() || weapon.is_bursting() {
             let was_cocked = weapon.is_cocked();
             let was_trigger_pressed = weapon.is_trigger_pressed();
 
@@ -96,36 +147,89 @@ pub fn weapon(
                         });
                     }
                 }
+                WeaponFireResult::Overheated => {
+                    if !was_trigger_pressed {
+                        audio.queue(AudioPlay {
+                            path: "sounds/overheat".into(),
+                            volume: 0.6,
+                            source: Some(transform.translation.xy()),
+                            ..AudioPlay::DEFAULT
+                        });
+                    }
+                }
                 WeaponFireResult::NotReady => {}
                 WeaponFireResult::Fire => {
+                    let arm_wound_deviation = 1.0 + actor.arm_wound * Actor::ARM_WOUND_DEVIATION;
                     let mut transform = TransformLite::from(transform);
                     transform.translation += Vec2::from_length(BARREL_LENGTH, transform.direction);
 
-                    audio.queue(AudioPlay {
-                        path: "sounds/shot".into(),
-                        volume: 1.0,
-                        source: Some(transform.translation),
-                        ..AudioPlay::DEFAULT
-                    });
+                    if let Some(projectile) = projectile {
+                        audio.queue(AudioPlay {
+                            path: "sounds/shot".into(),
+                            volume: weapon.get_noise(),
+                            source: Some(transform.translation),
+                            ..AudioPlay::DEFAULT
+                        });
+
+                        noises.add(Noise {
+                            position: transform.translation,
+                            loudness: SHOT_NOISE * weapon.get_noise(),
+                            emitter: Some(entity),
+                            time: now,
+                        });
+
+                        for _ in 0..projectile.fragments {
+                            let deviation = weapon
+                                .generate_deviation(&mut data.rng, actor.actions.is_aiming())
+                                * arm_wound_deviation;
+                            let velocity = weapon.generate_velocity(&mut data.rng);
+
+                            commands.add(ProjectileSpawn {
+                                config: Arc::clone(&projectile),
+                                transform: TransformLite::new(
+                                    transform.translation.x,
+                                    transform.translation.y,
+                                    transform.direction + deviation,
+                                ),
+                                velocity,
+                                shooter: Some(entity),
+                                weapon: Some(Arc::clone(&weapon.config)),
+                            });
+                        }
+                    } else {
+                        audio.queue(AudioPlay {
+                            path: "sounds/laser".into(),
+                            volume: 0.8,
+                            source: Some(transform.translation),
+                            ..AudioPlay::DEFAULT
+                        });
 
-                    for _ in 0..weapon.config.projectile.fragments {
-                        let deviation = weapon.config.generate_deviation(&mut data.rng);
-                        let velocity = weapon.config.generate_velocity(&mut data.rng);
+                        noises.add(Noise {
+                            position: transform.translation,
+                            loudness: LASER_NOISE,
+                            emitter: Some(entity),
+                            time: now,
+                        });
 
-                        commands.add(ProjectileSpawn {
-                            config: weapon.config.projectile,
+                        let deviation = weapon
+                            .generate_deviation(&mut data.rng, actor.actions.is_aiming())
+                            * arm_wound_deviation;
+
+                        commands.add(HitscanFire {
+                            weapon: Arc::clone(&weapon.config),
                             transform: TransformLite::new(
                                 transform.translation.x,
                                 transform.translation.y,
                                 transform.direction + deviation,
                             ),
-                            velocity,
                             shooter: Some(entity),
                         });
                     }
 
                     let mut recoil = weapon.get_recoil() * actor.config.recoil_factor / actor.skill;
 
+                    weapon.add_bloom(recoil);
+
                     if data.rng.gen::<bool>() {
                         recoil = -recoil;
                     }
@@ -0,0 +1,23 @@
+use crate::{
+    command::WeaponSwitch,
+    component::{Weapon, WeaponInventory},
+};
+use bevy::prelude::{Commands, Entity, Query, Res, Time};
+use std::time::Duration;
+
+pub fn weapon_inventory(
+    mut query: Query<(Entity, &mut WeaponInventory, Option<&Weapon>)>,
+    mut commands: Commands,
+    time: Res<Time>,
+) {
+    let now = time.elapsed();
+
+    for (entity, mut inventory, weapon) in query.iter_mut() {
+        if let Some(slot) = inventory.take_switched(now) {
+            commands.add(WeaponSwitch { entity, slot });
+        } else if let Some(slot) = inventory.take_request() {
+            let holstering_time = weapon.map_or(Duration::ZERO, |w| w.config.get_switching_time());
+            inventory.start_switching(slot, now + holstering_time);
+        }
+    }
+}
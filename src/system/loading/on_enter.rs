@@ -1,4 +1,11 @@
-use crate::resource::{AssetStorage, Cache};
+use crate::{
+    component::{ActorConfig, ArmorConfig, ExplosiveConfig, ProjectileConfig, WeaponConfig},
+    data::{
+        load_actors, load_armor, load_explosives, load_factions, load_weapons, ACTORS_PATH,
+        AMMUNITION_PATH, ARMOR_PATH, EXPLOSIVES_PATH, FACTIONS_PATH, WEAPONS_PATH,
+    },
+    resource::{AssetStorage, Cache, Factions, Registry},
+};
 use bevy::{
     prelude::{shape::Quad, AssetServer, Assets, Image, Mesh, Res, ResMut},
     render::render_resource::Extent3d,
@@ -10,11 +17,22 @@ pub fn on_enter(
     mut images: ResMut<Assets<Image>>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut cache: ResMut<Cache>,
+    mut ammunition: ResMut<Registry<ProjectileConfig>>,
+    mut weapons: ResMut<Registry<WeaponConfig>>,
+    mut explosives: ResMut<Registry<ExplosiveConfig>>,
+    mut armor: ResMut<Registry<ArmorConfig>>,
+    mut actors: ResMut<Registry<ActorConfig>>,
+    mut factions: ResMut<Factions>,
 ) {
     log::info!("Loading...");
     asset_storage.load(&asset_server);
     init_dummy_image(&mut images, &mut cache);
     init_dummy_mesh(&mut meshes, &mut cache);
+    init_weapons(&mut ammunition, &mut weapons);
+    init_explosives(&ammunition, &mut explosives);
+    init_armor(&mut armor);
+    init_actors(&mut actors);
+    init_factions(&mut factions);
 }
 
 fn init_dummy_image(images: &mut Assets<Image>, cache: &mut Cache) {
@@ -32,3 +50,76 @@ fn init_dummy_image(images: &mut Assets<Image>, cache: &mut Cache) {
 fn init_dummy_mesh(meshes: &mut Assets<Mesh>, cache: &mut Cache) {
     cache.dummy_mesh = Some(meshes.add(Mesh::from(Quad::default())));
 }
+
+fn init_weapons(ammunition: &mut Registry<ProjectileConfig>, weapons: &mut Registry<WeaponConfig>) {
+    match load_weapons(AMMUNITION_PATH, WEAPONS_PATH) {
+        Ok((ammunition_loaded, weapons_loaded)) => {
+            if weapons_loaded.is_empty() {
+                log::warn!("No weapons defined in {}", WEAPONS_PATH);
+            }
+
+            log::info!(
+                "Loaded {} ammunition types and {} weapons",
+                ammunition_loaded.len(),
+                weapons_loaded.len(),
+            );
+
+            *ammunition = ammunition_loaded;
+            *weapons = weapons_loaded;
+        }
+        Err(error) => {
+            log::error!("{:?}", error);
+        }
+    }
+}
+
+fn init_explosives(
+    ammunition: &Registry<ProjectileConfig>,
+    explosives: &mut Registry<ExplosiveConfig>,
+) {
+    match load_explosives(EXPLOSIVES_PATH, ammunition) {
+        Ok(explosives_loaded) => {
+            log::info!("Loaded {} explosives", explosives_loaded.len());
+            *explosives = explosives_loaded;
+        }
+        Err(error) => {
+            log::error!("{:?}", error);
+        }
+    }
+}
+
+fn init_armor(armor: &mut Registry<ArmorConfig>) {
+    match load_armor(ARMOR_PATH) {
+        Ok(armor_loaded) => {
+            log::info!("Loaded {} armor types", armor_loaded.len());
+            *armor = armor_loaded;
+        }
+        Err(error) => {
+            log::error!("{:?}", error);
+        }
+    }
+}
+
+fn init_actors(actors: &mut Registry<ActorConfig>) {
+    match load_actors(ACTORS_PATH) {
+        Ok(actors_loaded) => {
+            log::info!("Loaded {} actors", actors_loaded.len());
+            *actors = actors_loaded;
+        }
+        Err(error) => {
+            log::error!("{:?}", error);
+        }
+    }
+}
+
+fn init_factions(factions: &mut Factions) {
+    match load_factions(FACTIONS_PATH) {
+        Ok(factions_loaded) => {
+            log::info!("Loaded faction relationships");
+            *factions = factions_loaded;
+        }
+        Err(error) => {
+            log::error!("{:?}", error);
+        }
+    }
+}